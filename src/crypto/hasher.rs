@@ -1,23 +1,56 @@
 use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use ark_std::{vec, vec::Vec};
 use sha2::{Digest, Sha256};
 
+// Everything is hashed over its canonical compressed encoding: one fixed-width
+// little-endian word per field element, and a u64 length prefix in front of
+// vectors and polynomials, so a whole trace row or polynomial can be one leaf.
+pub fn hash_serializable<F: PrimeField, T: CanonicalSerialize + ?Sized>(data: &T) -> F {
+    let mut bytes = Vec::with_capacity(data.compressed_size());
+    data.serialize_compressed(&mut bytes)
+        .expect("serializing into a Vec cannot fail");
+    F::from_le_bytes_mod_order(&Sha256::digest(&bytes))
+}
+
 pub fn hash<F: PrimeField>(data: &F) -> F {
-    let mut hasher = Sha256::new();
-    hasher.update(data.to_string());
-    let h = hasher.finalize();
-    F::from_le_bytes_mod_order(&h)
+    hash_serializable(data)
 }
 
+// Fixed-width words need no separators, so [1, 23] and [12, 3] hash differently.
 pub fn hash_slice<F: PrimeField>(data: &[F]) -> F {
     let mut hasher = Sha256::new();
-    data.iter().for_each(|d| hasher.update(d.to_string()));
-    let h = hasher.finalize();
-    F::from_le_bytes_mod_order(&h)
+    let mut bytes = Vec::new();
+    for d in data {
+        bytes.clear();
+        d.serialize_compressed(&mut bytes)
+            .expect("serializing into a Vec cannot fail");
+        hasher.update(&bytes);
+    }
+    F::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+// Merkle tree internal nodes hash a tag byte ahead of their children. A leaf
+// hashes one bare element, so the two inputs always differ in length and a node
+// can never be passed off as a leaf, nor a leaf as a node.
+const NODE_TAG: u8 = 1;
+
+pub fn hash_node<F: PrimeField>(children: &[F]) -> F {
+    let mut bytes = vec![NODE_TAG];
+    for child in children {
+        child
+            .serialize_compressed(&mut bytes)
+            .expect("serializing into a Vec cannot fail");
+    }
+    F::from_le_bytes_mod_order(&Sha256::digest(&bytes))
 }
 
 #[cfg(test)]
 mod tests {
+    use ark_poly::{DenseUVPolynomial, univariate::DensePolynomial};
+
     use super::*;
+    use crate::crypto::merkle::{MerkleTree, verify_merkle_proof};
     use crate::field::Fq;
 
     #[test]
@@ -32,4 +65,35 @@ mod tests {
         let b = Fq::from(43u64);
         assert_ne!(hash(&a), hash(&b));
     }
+
+    #[test]
+    fn test_hash_slice_unambiguous() {
+        let split_a = [Fq::from(1u64), Fq::from(23u64)];
+        let split_b = [Fq::from(12u64), Fq::from(3u64)];
+        assert_ne!(hash_slice(&split_a), hash_slice(&split_b));
+    }
+
+    #[test]
+    fn test_hash_rows_and_polynomials() {
+        let row = vec![Fq::from(1u64), Fq::from(2u64)];
+        let poly = DensePolynomial::from_coefficients_vec(row.clone());
+
+        // both encode as length prefix + coefficients
+        assert_eq!(
+            hash_serializable::<Fq, _>(&row),
+            hash_serializable::<Fq, _>(&poly)
+        );
+        assert_ne!(hash_serializable::<Fq, _>(&row), hash_slice(&row));
+    }
+
+    #[test]
+    fn test_merkle_root_is_stable() {
+        let leaves: Vec<Fq> = (0..8).map(|i| Fq::from(i as u64)).collect();
+        let tree = MerkleTree::new(leaves);
+        assert_eq!(tree.root(), Fq::from(15998558967406266083u64));
+
+        for i in 0..8 {
+            assert!(verify_merkle_proof(&tree.generate_proof(i)));
+        }
+    }
 }
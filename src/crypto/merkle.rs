@@ -1,8 +1,15 @@
 use ark_ff::PrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+};
+use ark_std::{marker::PhantomData, vec, vec::Vec};
 
-use crate::crypto::hasher::{hash, hash_slice};
+use crate::{
+    crypto::hasher::{hash, hash_node, hash_serializable},
+    serialize::read_vec,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, CanonicalSerialize)]
 pub struct MerkleProof<F: PrimeField> {
     pub index: usize,
     pub leaf_val: F,
@@ -10,24 +17,110 @@ pub struct MerkleProof<F: PrimeField> {
     pub root: F,
 }
 
+impl<F: PrimeField> MerkleProof<F> {
+    // Checks the proof against a root the verifier already trusts; the root the
+    // proof carries is only a claim.
+    pub fn verify(&self, root: F) -> bool {
+        self.root == root && verify_merkle_proof(self)
+    }
+}
+
+impl<F: PrimeField> Valid for MerkleProof<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.leaf_val.check()?;
+        self.auth_path.check()?;
+        self.root.check()
+    }
+}
+
+// Vectors are decoded with read_vec.
+impl<F: PrimeField> CanonicalDeserialize for MerkleProof<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self {
+            index: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            leaf_val: F::deserialize_with_mode(&mut reader, compress, validate)?,
+            auth_path: read_vec(&mut reader, compress, validate)?,
+            root: F::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+// Opening of the consecutive leaves start..start + leaves.len(). Siblings are only
+// stored where the range boundary cuts a pair, left one before right one, level
+// by level, so adjacent leaves share the rest of their paths.
+#[derive(Debug, Clone, CanonicalSerialize)]
+pub struct RangeProof<F: PrimeField> {
+    pub start: usize,
+    pub num_leaves: usize,
+    pub leaves: Vec<F>,
+    pub siblings: Vec<F>,
+}
+
+impl<F: PrimeField> Valid for RangeProof<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.leaves.check()?;
+        self.siblings.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for RangeProof<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self {
+            start: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            num_leaves: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            leaves: read_vec(&mut reader, compress, validate)?,
+            siblings: read_vec(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+// Maps a leaf value to its node in the bottom level of the tree.
+pub trait LeafHasher<F: PrimeField> {
+    fn hash_leaf(leaf: &F) -> F;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256LeafHasher;
+
+impl<F: PrimeField> LeafHasher<F> for Sha256LeafHasher {
+    fn hash_leaf(leaf: &F) -> F {
+        hash(leaf)
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct MerkleTree<F: PrimeField> {
+pub struct MerkleTree<F: PrimeField, H: LeafHasher<F> = Sha256LeafHasher> {
     internal_nodes: Vec<Vec<F>>,
     pub leaves: Vec<F>,
     depth: usize,
+    _hasher: PhantomData<H>,
 }
 
 impl<F: PrimeField> MerkleTree<F> {
-    pub fn new(mut leaves: Vec<F>) -> Self {
+    pub fn new(leaves: Vec<F>) -> Self {
+        Self::with_leaf_hasher(leaves)
+    }
+}
+
+impl<F: PrimeField, H: LeafHasher<F>> MerkleTree<F, H> {
+    pub fn with_leaf_hasher(mut leaves: Vec<F>) -> Self {
         let new_len = leaves.len().next_power_of_two();
         let depth = new_len.ilog2() as usize;
 
-        let first_level = leaves.iter().map(hash).collect::<Vec<_>>();
+        let first_level = leaves.iter().map(H::hash_leaf).collect::<Vec<_>>();
 
         let mut internal_nodes = vec![first_level];
 
         for i in 0..depth {
-            let next_level = internal_nodes[i].chunks(2).map(hash_slice).collect();
+            let next_level = internal_nodes[i].chunks(2).map(hash_node).collect();
             internal_nodes.push(next_level);
         }
 
@@ -37,6 +130,26 @@ impl<F: PrimeField> MerkleTree<F> {
             internal_nodes,
             leaves,
             depth,
+            _hasher: PhantomData,
+        }
+    }
+
+    // Replaces one leaf and rehashes only the nodes on its path to the root.
+    pub fn update_leaf(&mut self, index: usize, value: F) {
+        assert!(
+            index < self.internal_nodes[0].len(),
+            "leaf index out of range"
+        );
+        self.leaves[index] = value;
+        self.internal_nodes[0][index] = H::hash_leaf(&value);
+
+        let mut curr_index = index;
+        for i in 0..self.depth {
+            let start = curr_index - curr_index % 2;
+            let end = (start + 2).min(self.internal_nodes[i].len());
+            let parent = hash_node(&self.internal_nodes[i][start..end]);
+            curr_index /= 2;
+            self.internal_nodes[i + 1][curr_index] = parent;
         }
     }
 
@@ -44,6 +157,39 @@ impl<F: PrimeField> MerkleTree<F> {
         self.internal_nodes.last().unwrap()[0]
     }
 
+    // Leaves actually committed; `leaves` is padded up to a power of two.
+    pub fn num_leaves(&self) -> usize {
+        self.internal_nodes[0].len()
+    }
+
+    pub fn open_range(&self, start: usize, len: usize) -> RangeProof<F> {
+        let num_leaves = self.num_leaves();
+        assert!(
+            len > 0 && start.checked_add(len).is_some_and(|end| end <= num_leaves),
+            "leaf range out of bounds"
+        );
+
+        let mut siblings = Vec::new();
+        let (mut lo, mut hi) = (start, start + len - 1);
+        for level in &self.internal_nodes[..self.depth] {
+            if lo % 2 == 1 {
+                siblings.push(level[lo - 1]);
+            }
+            if hi.is_multiple_of(2) && hi + 1 < level.len() {
+                siblings.push(level[hi + 1]);
+            }
+            lo /= 2;
+            hi /= 2;
+        }
+
+        RangeProof {
+            start,
+            num_leaves,
+            leaves: self.leaves[start..start + len].to_vec(),
+            siblings,
+        }
+    }
+
     pub fn generate_proof(&self, index: usize) -> MerkleProof<F> {
         let leaf_val = self.leaves[index];
         let mut hash_proof = Vec::with_capacity(self.depth);
@@ -68,22 +214,172 @@ impl<F: PrimeField> MerkleTree<F> {
 }
 
 pub fn verify_merkle_proof<F: PrimeField>(proof: &MerkleProof<F>) -> bool {
-    let mut curr_idx = proof.index;
-    let mut curr_hash = hash(&proof.leaf_val);
+    verify_merkle_proof_with::<F, Sha256LeafHasher>(proof)
+}
 
-    for i in 0..proof.auth_path.len() {
-        let neighbour = proof.auth_path[i];
+// Also pins the path length and keeps the index within 2^height leaves, so a
+// proof cannot claim a position in a tree of a different height.
+pub fn verify_with_height<F: PrimeField>(proof: &MerkleProof<F>, expected_height: usize) -> bool {
+    has_height(proof, expected_height) && verify_merkle_proof(proof)
+}
 
-        if curr_idx.is_multiple_of(2) {
-            curr_hash = hash_slice(&[curr_hash, neighbour]);
+// The shape half of verify_with_height, without hashing the path.
+pub fn has_height<F: PrimeField>(proof: &MerkleProof<F>, expected_height: usize) -> bool {
+    proof.auth_path.len() == expected_height
+        && u32::try_from(expected_height)
+            .ok()
+            .and_then(|height| proof.index.checked_shr(height))
+            .unwrap_or(0)
+            == 0
+}
+
+pub fn verify_merkle_proof_with<F: PrimeField, H: LeafHasher<F>>(proof: &MerkleProof<F>) -> bool {
+    let mut verifier = MerkleVerifier::<F, H>::with_leaf_hasher(proof.index, &proof.leaf_val);
+    for sibling in &proof.auth_path {
+        verifier.step(sibling);
+    }
+    verifier.finalize(proof.root)
+}
+
+// Walks an authentication path one sibling at a time, so a path can be checked
+// while it is still being read.
+#[derive(Debug, Clone)]
+pub struct MerkleVerifier<F: PrimeField, H: LeafHasher<F> = Sha256LeafHasher> {
+    index: usize,
+    hash: F,
+    _hasher: PhantomData<H>,
+}
+
+impl<F: PrimeField> MerkleVerifier<F> {
+    pub fn new(index: usize, leaf: &F) -> Self {
+        Self::with_leaf_hasher(index, leaf)
+    }
+}
+
+impl<F: PrimeField, H: LeafHasher<F>> MerkleVerifier<F, H> {
+    pub fn with_leaf_hasher(index: usize, leaf: &F) -> Self {
+        Self {
+            index,
+            hash: H::hash_leaf(leaf),
+            _hasher: PhantomData,
+        }
+    }
+
+    pub fn step(&mut self, sibling: &F) -> &mut Self {
+        self.hash = if self.index.is_multiple_of(2) {
+            hash_node(&[self.hash, *sibling])
         } else {
-            curr_hash = hash_slice(&[neighbour, curr_hash]);
+            hash_node(&[*sibling, self.hash])
+        };
+        self.index /= 2;
+        self
+    }
+
+    pub fn finalize(&self, root: F) -> bool {
+        self.hash == root
+    }
+}
+
+// `num_leaves` is the size of the committed tree, known to the verifier like the
+// root; the count the proof carries is only a claim and must agree with it.
+pub fn verify_range<F: PrimeField>(root: F, num_leaves: usize, proof: &RangeProof<F>) -> bool {
+    verify_range_with::<F, Sha256LeafHasher>(root, num_leaves, proof)
+}
+
+pub fn verify_range_with<F: PrimeField, H: LeafHasher<F>>(
+    root: F,
+    num_leaves: usize,
+    proof: &RangeProof<F>,
+) -> bool {
+    let in_bounds = proof
+        .start
+        .checked_add(proof.leaves.len())
+        .is_some_and(|end| end <= num_leaves);
+    if proof.num_leaves != num_leaves || proof.leaves.is_empty() || !in_bounds {
+        return false;
+    }
+
+    let mut nodes: Vec<F> = proof.leaves.iter().map(H::hash_leaf).collect();
+    let mut siblings = proof.siblings.iter();
+    let mut lo = proof.start;
+    let mut level_len = num_leaves;
+
+    // levels shrink like the tree's: an unpaired last node is hashed alone
+    while level_len > 1 {
+        let hi = lo + nodes.len() - 1;
+        if lo % 2 == 1 {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            nodes.insert(0, *sibling);
+            lo -= 1;
+        }
+        if hi.is_multiple_of(2) && hi + 1 < level_len {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            nodes.push(*sibling);
         }
 
-        curr_idx /= 2;
+        nodes = nodes.chunks(2).map(hash_node).collect();
+        lo /= 2;
+        level_len = level_len.div_ceil(2);
     }
 
-    curr_hash == proof.root
+    siblings.next().is_none() && nodes == [root]
+}
+
+// One leaf per item, valued at the hash of the item's canonical encoding.
+pub fn commit_serializable<F: PrimeField, T: CanonicalSerialize>(items: &[T]) -> MerkleTree<F> {
+    MerkleTree::new(items.iter().map(hash_serializable).collect())
+}
+
+pub fn verify_serializable<F: PrimeField, T: CanonicalSerialize + ?Sized>(
+    item: &T,
+    proof: &MerkleProof<F>,
+) -> bool {
+    proof.leaf_val == hash_serializable(item) && verify_merkle_proof(proof)
+}
+
+// One leaf per trace row, each hashed with its length prefix. Pass the transposed
+// table to commit column by column instead.
+pub fn commit_rows<F: PrimeField>(rows: &[Vec<F>]) -> MerkleTree<F> {
+    commit_serializable(rows)
+}
+
+pub fn verify_row<F: PrimeField>(row: &[F], proof: &MerkleProof<F>) -> bool {
+    verify_serializable(row, proof)
+}
+
+// One tree per column, leaves in row order. The roots are what gets published.
+pub fn commit_columns<F: PrimeField>(columns: &[Vec<F>]) -> Vec<MerkleTree<F>> {
+    columns.iter().cloned().map(MerkleTree::new).collect()
+}
+
+// The openings of one row across every committed column, in column order.
+pub fn open_row<F: PrimeField>(trees: &[MerkleTree<F>], row: usize) -> Vec<MerkleProof<F>> {
+    trees.iter().map(|tree| tree.generate_proof(row)).collect()
+}
+
+// Checks an open_row result against the published column roots.
+pub fn verify_row_openings<F: PrimeField>(
+    roots: &[F],
+    row: usize,
+    openings: &[MerkleProof<F>],
+) -> bool {
+    roots.len() == openings.len()
+        && roots
+            .iter()
+            .zip(openings)
+            .all(|(root, proof)| proof.index == row && proof.verify(*root))
+}
+
+// Checks each proof against its expected root; Err carries the index of the first failure.
+pub fn verify_all<F: PrimeField>(checks: &[(F, &MerkleProof<F>)]) -> Result<(), usize> {
+    match checks.iter().position(|(root, proof)| !proof.verify(*root)) {
+        Some(i) => Err(i),
+        None => Ok(()),
+    }
 }
 
 #[cfg(test)]
@@ -91,6 +387,202 @@ mod tests {
     use super::*;
     use crate::field::Fq;
 
+    #[test]
+    fn test_open_range() {
+        let tree = MerkleTree::new((0..16u64).map(Fq::from).collect());
+        let proof = tree.open_range(5, 4);
+        assert_eq!(proof.leaves, (5..9u64).map(Fq::from).collect::<Vec<_>>());
+        assert!(verify_range(tree.root(), tree.num_leaves(), &proof));
+
+        // cheaper than four single openings of four siblings each
+        assert!(proof.siblings.len() < 4 * 4);
+
+        let mut tampered = proof.clone();
+        tampered.leaves[1] += Fq::from(1);
+        assert!(!verify_range(tree.root(), tree.num_leaves(), &tampered));
+
+        let mut shifted = proof.clone();
+        shifted.start = 4;
+        assert!(!verify_range(tree.root(), tree.num_leaves(), &shifted));
+
+        let mut truncated = proof;
+        truncated.siblings.pop();
+        assert!(!verify_range(tree.root(), tree.num_leaves(), &truncated));
+    }
+
+    #[test]
+    fn test_open_range_edges() {
+        // 11 leaves: the last level-0 node has no partner
+        let tree = MerkleTree::new((0..11u64).map(Fq::from).collect());
+        for (start, len) in [(0, 11), (8, 3), (10, 1), (0, 1), (3, 5)] {
+            assert!(verify_range(
+                tree.root(),
+                tree.num_leaves(),
+                &tree.open_range(start, len)
+            ));
+        }
+
+        let single = MerkleTree::new(vec![Fq::from(7)]);
+        assert!(verify_range(
+            single.root(),
+            single.num_leaves(),
+            &single.open_range(0, 1)
+        ));
+    }
+
+    #[test]
+    fn test_range_proof_cannot_change_tree_size() {
+        let tree = MerkleTree::new([10u64, 20, 30].map(Fq::from).to_vec());
+        let level_one = hash_node(&[hash(&Fq::from(10)), hash(&Fq::from(20))]);
+
+        // claims a 2-leaf tree, whose leaf 1 sits under the root beside level_one
+        let forged = RangeProof {
+            start: 1,
+            num_leaves: 2,
+            leaves: vec![hash(&Fq::from(30))],
+            siblings: vec![level_one],
+        };
+        assert!(!verify_range(tree.root(), tree.num_leaves(), &forged));
+        // a lone node no longer hashes like a leaf, so even trusting the claimed
+        // size does not help
+        assert!(!verify_range(tree.root(), forged.num_leaves, &forged));
+
+        let honest = tree.open_range(1, 1);
+        assert!(verify_range(tree.root(), 3, &honest));
+        assert!(!verify_range(tree.root(), 4, &honest));
+    }
+
+    #[test]
+    fn test_range_proof_huge_length_prefix() {
+        let mut bytes = Vec::new();
+        for word in [0, 4, u64::MAX / 2] {
+            bytes.extend(word.to_le_bytes());
+        }
+        assert!(RangeProof::<Fq>::deserialize_compressed(&bytes[..]).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "leaf range out of bounds")]
+    fn test_open_range_out_of_bounds() {
+        let tree = MerkleTree::new((0..8u64).map(Fq::from).collect());
+        tree.open_range(6, 3);
+    }
+
+    #[test]
+    fn test_proof_verify_against_root() {
+        let tree = MerkleTree::new((0..8u64).map(Fq::from).collect());
+        let other = MerkleTree::new((10..18u64).map(Fq::from).collect());
+
+        let proof = tree.generate_proof(3);
+        assert!(proof.verify(tree.root()));
+        assert!(!proof.verify(other.root()));
+
+        // a self-consistent proof from another tree is rejected by the trusted root
+        let foreign = other.generate_proof(3);
+        assert!(verify_merkle_proof(&foreign));
+        assert!(!foreign.verify(tree.root()));
+
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        let decoded = MerkleProof::<Fq>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(decoded.index, 3);
+        assert_eq!(decoded.leaf_val, Fq::from(3));
+        assert!(decoded.verify(tree.root()));
+    }
+
+    #[test]
+    fn test_proof_encoding_is_pinned() {
+        // usize fields and length prefixes are u64 words, field elements their
+        // canonical value, all little-endian, independent of the platform
+        let proof = MerkleProof {
+            index: 2,
+            leaf_val: Fq::from(1),
+            auth_path: vec![-Fq::from(1)],
+            root: Fq::from(0x0102_0304_0506_0708u64),
+        };
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+
+        let expected: Vec<u8> = [
+            [2, 0, 0, 0, 0, 0, 0, 0],
+            [1, 0, 0, 0, 0, 0, 0, 0],
+            [1, 0, 0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff],
+            [8, 7, 6, 5, 4, 3, 2, 1],
+        ]
+        .concat();
+        assert_eq!(bytes, expected);
+
+        let decoded = MerkleProof::<Fq>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(decoded.auth_path, proof.auth_path);
+        assert_eq!(decoded.root, proof.root);
+    }
+
+    #[test]
+    fn test_commit_columns() {
+        let columns: Vec<Vec<Fq>> = (0..3u64)
+            .map(|c| (0..8u64).map(|i| Fq::from(c * 10 + i)).collect())
+            .collect();
+        let trees = commit_columns(&columns);
+        let roots: Vec<Fq> = trees.iter().map(MerkleTree::root).collect();
+
+        for (root, column) in roots.iter().zip(&columns) {
+            assert_eq!(*root, MerkleTree::new(column.clone()).root());
+        }
+
+        let openings = open_row(&trees, 5);
+        let values: Vec<Fq> = openings.iter().map(|p| p.leaf_val).collect();
+        assert_eq!(values, vec![Fq::from(5), Fq::from(15), Fq::from(25)]);
+        assert!(verify_row_openings(&roots, 5, &openings));
+        assert!(!verify_row_openings(&roots, 4, &openings));
+        assert!(!verify_row_openings(&roots[..2], 5, &openings));
+
+        let mut swapped = openings;
+        swapped.swap(0, 1);
+        assert!(!verify_row_openings(&roots, 5, &swapped));
+    }
+
+    #[test]
+    fn test_verify_with_height() {
+        let tree = MerkleTree::new((0..8u64).map(Fq::from).collect());
+        let proof = tree.generate_proof(6);
+        assert!(verify_with_height(&proof, 3));
+        assert!(!verify_with_height(&proof, 2));
+        assert!(!verify_with_height(&proof, 4));
+
+        // only the low bits of the index steer the path, so index 14 replays the
+        // proof for leaf 6 unless the height is pinned
+        let mut spoofed = tree.generate_proof(6);
+        spoofed.index += 8;
+        assert!(verify_merkle_proof(&spoofed));
+        assert!(!verify_with_height(&spoofed, 3));
+        assert!(has_height(&proof, 3));
+        assert!(!has_height(&spoofed, 3));
+    }
+
+    #[test]
+    fn test_incremental_verifier_matches_batch() {
+        let tree = MerkleTree::new((0..8u64).map(Fq::from).collect());
+
+        for i in 0..8 {
+            let proof = tree.generate_proof(i);
+            let mut verifier = MerkleVerifier::new(proof.index, &proof.leaf_val);
+            for sibling in &proof.auth_path {
+                verifier.step(sibling);
+            }
+            assert_eq!(verifier.finalize(tree.root()), verify_merkle_proof(&proof));
+            assert!(verifier.finalize(tree.root()));
+        }
+
+        let proof = tree.generate_proof(5);
+        let mut verifier = MerkleVerifier::new(5, &Fq::from(6));
+        verifier
+            .step(&proof.auth_path[0])
+            .step(&proof.auth_path[1])
+            .step(&proof.auth_path[2]);
+        assert!(!verifier.finalize(tree.root()));
+    }
+
     #[test]
     fn test_merkle_commit_and_verify() {
         let leaves: Vec<Fq> = (0..8).map(|i| Fq::from(i as u64)).collect();
@@ -113,4 +605,116 @@ mod tests {
         proof.leaf_val += Fq::from(1u64); // tamper
         assert!(!verify_merkle_proof(&proof));
     }
+
+    #[test]
+    fn test_verify_all() {
+        let tree_a = MerkleTree::new((0..8).map(|i| Fq::from(i as u64)).collect());
+        let tree_b = MerkleTree::new((8..16).map(|i| Fq::from(i as u64)).collect());
+
+        let mut proofs = Vec::new();
+        for i in 0..8 {
+            proofs.push((tree_a.root(), tree_a.generate_proof(i)));
+            proofs.push((tree_b.root(), tree_b.generate_proof(i)));
+        }
+        let checks: Vec<_> = proofs.iter().map(|(root, proof)| (*root, proof)).collect();
+        assert_eq!(verify_all(&checks), Ok(()));
+
+        let mut tampered = proofs.clone();
+        tampered[11].1.leaf_val += Fq::from(1u64);
+        let checks: Vec<_> = tampered
+            .iter()
+            .map(|(root, proof)| (*root, proof))
+            .collect();
+        assert_eq!(verify_all(&checks), Err(11));
+
+        // a valid opening of the wrong tree is rejected too
+        let mut swapped = proofs.clone();
+        swapped[4].0 = tree_b.root();
+        let checks: Vec<_> = swapped.iter().map(|(root, proof)| (*root, proof)).collect();
+        assert_eq!(verify_all(&checks), Err(4));
+    }
+
+    #[test]
+    fn test_custom_leaf_hasher() {
+        use crate::crypto::hasher::hash_slice;
+
+        #[derive(Debug, Clone)]
+        struct Tagged;
+
+        impl LeafHasher<Fq> for Tagged {
+            fn hash_leaf(leaf: &Fq) -> Fq {
+                hash_slice(&[Fq::from(0x6c656166u64), *leaf])
+            }
+        }
+
+        let leaves: Vec<Fq> = (0..8).map(|i| Fq::from(i as u64)).collect();
+        let default_tree = MerkleTree::new(leaves.clone());
+        let tagged_tree = MerkleTree::<Fq, Tagged>::with_leaf_hasher(leaves);
+        assert_ne!(tagged_tree.root(), default_tree.root());
+
+        let proof = tagged_tree.generate_proof(3);
+        assert!(verify_merkle_proof_with::<Fq, Tagged>(&proof));
+        assert!(!verify_merkle_proof(&proof));
+    }
+
+    #[test]
+    fn test_commit_rows() {
+        let trace: Vec<Vec<Fq>> = (0..4u64)
+            .map(|i| vec![Fq::from(i), Fq::from(i * i), Fq::from(i + 10)])
+            .collect();
+        let tree = commit_rows(&trace);
+
+        let proof = tree.generate_proof(2);
+        assert_eq!(proof.root, tree.root());
+        assert!(verify_row(&trace[2], &proof));
+        assert!(!verify_row(&trace[1], &proof));
+
+        let mut tampered = trace[2].clone();
+        tampered[1] += Fq::from(1u64);
+        assert!(!verify_row(&tampered, &proof));
+    }
+
+    #[test]
+    fn test_update_leaf_matches_rebuild() {
+        for size in [8, 6] {
+            let mut leaves: Vec<Fq> = (0..size).map(|i| Fq::from(i as u64)).collect();
+            let mut tree = MerkleTree::new(leaves.clone());
+
+            for (index, value) in [(0, 100u64), (5, 200), (3, 300)] {
+                tree.update_leaf(index, Fq::from(value));
+                leaves[index] = Fq::from(value);
+
+                let rebuilt = MerkleTree::new(leaves.clone());
+                assert_eq!(tree.root(), rebuilt.root());
+            }
+        }
+    }
+
+    #[test]
+    fn test_commit_serializable() {
+        #[derive(CanonicalSerialize)]
+        struct Transfer {
+            from: u32,
+            to: u32,
+            amount: Fq,
+        }
+
+        let transfers: Vec<Transfer> = (0..5u32)
+            .map(|i| Transfer {
+                from: i,
+                to: i + 1,
+                amount: Fq::from(100 * i as u64),
+            })
+            .collect();
+        let tree = commit_serializable::<Fq, _>(&transfers);
+
+        let proof = tree.generate_proof(3);
+        assert!(verify_serializable(&transfers[3], &proof));
+
+        let forged = Transfer {
+            amount: Fq::from(1_000_000u64),
+            ..transfers[3]
+        };
+        assert!(!verify_serializable(&forged, &proof));
+    }
 }
@@ -1,8 +1,11 @@
-use std::marker::PhantomData;
-
 use ark_ff::PrimeField;
+use ark_std::{marker::PhantomData, string::ToString, vec::Vec};
 use sha2::{Digest, Sha256};
 
+// Leading zero bits a grinding nonce can have: the width of a SHA256 digest.
+pub const MAX_WORK_BITS: u32 = 256;
+
+#[derive(Clone)]
 pub struct Transcript<F: PrimeField> {
     hasher: Sha256,
     _phantom: PhantomData<F>,
@@ -19,13 +22,55 @@ impl<F: PrimeField> Transcript<F> {
         }
     }
 
+    // Independent copy of the current state, for speculative branches.
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
     pub fn digest(&mut self, value: F) {
         self.hasher.update(value.to_string());
     }
 
+    // The challenge generate_a_challenge would return, without advancing the state.
+    pub fn peek_challenge(&self) -> F {
+        Self::reduce(&self.fingerprint())
+    }
+
+    // Hash of everything absorbed so far. A prover can publish it at some point of
+    // the protocol so the verifier detects a desynchronized transcript right there.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        self.hasher.clone().finalize().into()
+    }
+
+    pub fn matches(&self, expected_fingerprint: &[u8]) -> bool {
+        self.fingerprint().as_slice() == expected_fingerprint
+    }
+
+    // Samples exactly as a challenge would, but from a fixed byte seed instead of
+    // the running state; meant for reproducible (golden) tests.
+    pub fn sample_from_seed(seed: &[u8]) -> F {
+        Self::reduce(&Sha256::digest(seed))
+    }
+
+    // Digest bytes one challenge is reduced from: the modulus width plus 16 bytes,
+    // which keeps the bias of the reduction below 2^-128 (24 bytes for Goldilocks).
+    // Fields wider than 128 bits get the whole 32-byte digest.
+    pub fn challenge_bytes() -> usize {
+        ((F::MODULUS_BIT_SIZE as usize).div_ceil(8) + 16).min(32)
+    }
+
+    // Little-endian, like the serialized form of an element and the leaf hashes,
+    // so the bytes of an element below the modulus sample back to that element.
+    pub fn sample_bytes(bytes: &[u8]) -> F {
+        F::from_le_bytes_mod_order(&bytes[..bytes.len().min(Self::challenge_bytes())])
+    }
+
+    fn reduce(digest: &[u8]) -> F {
+        Self::sample_bytes(digest)
+    }
+
     pub fn generate_a_challenge(&mut self) -> F {
-        let value = self.hasher.clone().finalize();
-        let f = F::from_be_bytes_mod_order(&value);
+        let f = self.peek_challenge();
         self.hasher.update(f.to_string());
         f
     }
@@ -40,6 +85,44 @@ impl<F: PrimeField> Transcript<F> {
             })
             .collect()
     }
+
+    // Grinding: find a nonce whose hash with the current state has `difficulty`
+    // leading zero bits, then absorb it.
+    pub fn prove_work(&mut self, difficulty: u32) -> u64 {
+        // a SHA256 digest has no more zero bits to offer, so the search would never end
+        assert!(
+            difficulty <= MAX_WORK_BITS,
+            "difficulty {difficulty} exceeds the {MAX_WORK_BITS}-bit digest"
+        );
+        let nonce = (0..u64::MAX)
+            .find(|&nonce| self.work_bits(nonce) >= difficulty)
+            .expect("no nonce satisfies the difficulty");
+        self.digest(F::from(nonce));
+        nonce
+    }
+
+    pub fn verify_work(&mut self, difficulty: u32, nonce: u64) -> bool {
+        if difficulty > MAX_WORK_BITS || self.work_bits(nonce) < difficulty {
+            return false;
+        }
+        self.digest(F::from(nonce));
+        true
+    }
+
+    fn work_bits(&self, nonce: u64) -> u32 {
+        let mut hasher = self.hasher.clone();
+        hasher.update(nonce.to_le_bytes());
+        let h = hasher.finalize();
+
+        let mut zeros = 0;
+        for byte in h.iter() {
+            zeros += byte.leading_zeros();
+            if *byte != 0 {
+                break;
+            }
+        }
+        zeros
+    }
 }
 
 #[cfg(test)]
@@ -68,4 +151,165 @@ mod tests {
 
         assert_ne!(t1.generate_a_challenge(), t2.generate_a_challenge());
     }
+
+    #[test]
+    fn test_peek_challenge() {
+        let mut t = Transcript::<Fq>::new(Fq::from(0u64));
+        t.digest(Fq::from(42u64));
+
+        let peeked = t.peek_challenge();
+        assert_eq!(t.peek_challenge(), peeked);
+        assert_eq!(t.generate_a_challenge(), peeked);
+        assert_ne!(t.peek_challenge(), peeked);
+    }
+
+    #[test]
+    fn test_running_state_matches_rehashing() {
+        // the hasher absorbs as it goes, so a challenge costs one clone and
+        // finalize, yet equals hashing everything absorbed so far from scratch
+        let mut t = Transcript::<Fq>::new(Fq::from(0u64));
+        let mut absorbed = Fq::from(0u64).to_string();
+        for value in [3u64, 1, 4, 1, 5] {
+            t.digest(Fq::from(value));
+            absorbed.push_str(&Fq::from(value).to_string());
+
+            let from_scratch = Transcript::<Fq>::sample_from_seed(absorbed.as_bytes());
+            assert_eq!(t.peek_challenge(), from_scratch);
+        }
+
+        let challenge = t.generate_a_challenge();
+        absorbed.push_str(&challenge.to_string());
+        assert_eq!(
+            t.peek_challenge(),
+            Transcript::<Fq>::sample_from_seed(absorbed.as_bytes())
+        );
+    }
+
+    #[test]
+    fn test_sample_from_seed() {
+        let sample = Transcript::<Fq>::sample_from_seed(b"lumen-stark");
+        assert_eq!(sample, Transcript::<Fq>::sample_from_seed(b"lumen-stark"));
+        assert_eq!(sample, Fq::from(6213548932354656625u64));
+        assert_ne!(sample, Transcript::<Fq>::sample_from_seed(b"lumen-starl"));
+    }
+
+    #[test]
+    fn test_challenge_bytes_follow_the_field() {
+        use crate::field::test_fields::BabyBear;
+
+        assert_eq!(Transcript::<Fq>::challenge_bytes(), 24);
+        assert_eq!(Transcript::<BabyBear>::challenge_bytes(), 20);
+
+        let digest = Sha256::digest(b"lumen-stark");
+        assert_eq!(
+            Transcript::<Fq>::sample_from_seed(b"lumen-stark"),
+            Fq::from_le_bytes_mod_order(&digest[..24])
+        );
+        assert_eq!(
+            Transcript::<BabyBear>::sample_from_seed(b"lumen-stark"),
+            BabyBear::from_le_bytes_mod_order(&digest[..20])
+        );
+    }
+
+    #[test]
+    fn test_sample_bytes_round_trips_serialization() {
+        use ark_serialize::CanonicalSerialize;
+
+        for x in [
+            Fq::from(0u64),
+            Fq::from(1u64),
+            Fq::from(0x0102_0304u64),
+            -Fq::from(1u64),
+        ] {
+            let mut bytes = Vec::new();
+            x.serialize_compressed(&mut bytes).unwrap();
+            assert_eq!(Transcript::<Fq>::sample_bytes(&bytes), x);
+
+            // zero-extending to the full challenge width keeps the value
+            bytes.resize(Transcript::<Fq>::challenge_bytes(), 0);
+            assert_eq!(Transcript::<Fq>::sample_bytes(&bytes), x);
+        }
+    }
+
+    #[test]
+    fn test_fork() {
+        let mut parent = Transcript::<Fq>::new(Fq::from(0u64));
+        parent.digest(Fq::from(42u64));
+
+        let mut fork = parent.fork();
+        assert_eq!(
+            fork.fork().generate_a_challenge(),
+            parent.fork().generate_a_challenge()
+        );
+
+        fork.digest(Fq::from(7u64));
+        let fork_challenge = fork.generate_a_challenge();
+
+        let mut untouched = Transcript::<Fq>::new(Fq::from(0u64));
+        untouched.digest(Fq::from(42u64));
+        assert_eq!(
+            parent.generate_a_challenge(),
+            untouched.generate_a_challenge()
+        );
+        assert_ne!(fork_challenge, untouched.generate_a_challenge());
+    }
+
+    #[test]
+    fn test_proof_of_work() {
+        let difficulty = 12;
+        let mut prover = Transcript::<Fq>::new(Fq::from(0u64));
+        prover.digest(Fq::from(42u64));
+        let nonce = prover.prove_work(difficulty);
+
+        let mut verifier = Transcript::<Fq>::new(Fq::from(0u64));
+        verifier.digest(Fq::from(42u64));
+        assert!(verifier.verify_work(difficulty, nonce));
+        assert_eq!(
+            prover.generate_a_challenge(),
+            verifier.generate_a_challenge()
+        );
+    }
+
+    #[test]
+    fn test_proof_of_work_wrong_nonce() {
+        let difficulty = 12;
+        let mut prover = Transcript::<Fq>::new(Fq::from(0u64));
+        let nonce = prover.prove_work(difficulty);
+
+        // prove_work returns the first passing nonce
+        let mut verifier = Transcript::<Fq>::new(Fq::from(0u64));
+        assert!(nonce > 0);
+        assert!(!verifier.verify_work(difficulty, nonce - 1));
+    }
+
+    #[test]
+    fn test_verify_work_rejects_oversized_difficulty() {
+        let mut verifier = Transcript::<Fq>::new(Fq::from(0u64));
+        assert!(!verifier.verify_work(MAX_WORK_BITS + 1, 0));
+        assert!(!verifier.verify_work(u32::MAX, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the 256-bit digest")]
+    fn test_prove_work_rejects_oversized_difficulty() {
+        Transcript::<Fq>::new(Fq::from(0u64)).prove_work(MAX_WORK_BITS + 1);
+    }
+
+    #[test]
+    fn test_fingerprint_detects_desync() {
+        let mut prover = Transcript::<Fq>::new(Fq::from(0u64));
+        let mut verifier = Transcript::<Fq>::new(Fq::from(0u64));
+        let mut corrupted = Transcript::<Fq>::new(Fq::from(0u64));
+
+        for value in [3u64, 1, 4] {
+            prover.digest(Fq::from(value));
+            verifier.digest(Fq::from(value));
+            corrupted.digest(Fq::from(value + (value == 1) as u64));
+        }
+        let expected = prover.fingerprint();
+
+        assert!(verifier.matches(&expected));
+        assert!(!corrupted.matches(&expected));
+        assert!(!verifier.matches(&expected[..31]));
+    }
 }
@@ -0,0 +1,61 @@
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldError {
+    NotInvertible,
+    LengthMismatch { left: usize, right: usize },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolyError {
+    ZeroDivisor,
+    InexactDivision,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MPolyError {
+    PointTooShort { required: usize, got: usize },
+    ExponentOverflow { var: usize },
+    TooManyTerms { limit: usize },
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotInvertible => write!(f, "zero is not invertible"),
+            Self::LengthMismatch { left, right } => {
+                write!(f, "operands have lengths {left} and {right}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for PolyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroDivisor => write!(f, "division by the zero polynomial"),
+            Self::InexactDivision => write!(f, "division leaves a nonzero remainder"),
+        }
+    }
+}
+
+impl fmt::Display for MPolyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PointTooShort { required, got } => {
+                write!(
+                    f,
+                    "point has {got} coordinates, polynomial needs {required}"
+                )
+            }
+            Self::ExponentOverflow { var } => {
+                write!(f, "exponent of x_{var} overflows in multiplication")
+            }
+            Self::TooManyTerms { limit } => write!(f, "result exceeds {limit} terms"),
+        }
+    }
+}
+
+impl core::error::Error for FieldError {}
+impl core::error::Error for PolyError {}
+impl core::error::Error for MPolyError {}
@@ -0,0 +1,385 @@
+use ark_ff::{BigInteger, FftField, PrimeField};
+use ark_std::vec::Vec;
+
+use crate::error::FieldError;
+
+pub fn checked_inv<F: PrimeField>(x: F) -> Result<F, FieldError> {
+    x.inverse().ok_or(FieldError::NotInvertible)
+}
+
+// x^(p-2), the inverse by Fermat's little theorem. A fixed exponent, so the same
+// sequence of squarings and multiplications runs for every nonzero input; also a
+// cross-check on checked_inv, which goes through the extended Euclidean algorithm.
+pub fn inv_fermat<F: PrimeField>(x: F) -> Result<F, FieldError> {
+    if x.is_zero() {
+        return Err(FieldError::NotInvertible);
+    }
+    let mut exponent = F::MODULUS;
+    exponent.sub_with_borrow(&F::BigInt::from(2u64));
+    Ok(x.pow(exponent))
+}
+
+pub fn checked_div<F: PrimeField>(numerator: F, denominator: F) -> Result<F, FieldError> {
+    Ok(numerator * checked_inv(denominator)?)
+}
+
+// Σ a_i·b_i. Chunks go through ark-ff's fixed-size sum_of_products, which
+// interleaves the Montgomery reductions when the modulus leaves a spare bit in
+// the top limb; Goldilocks fills its limb, so there it is the plain fold.
+pub fn sum_of_products<F: PrimeField>(a: &[F], b: &[F]) -> Result<F, FieldError> {
+    check_lengths(a, b)?;
+
+    const CHUNK: usize = 8;
+    let mut a_chunks = a.chunks_exact(CHUNK);
+    let mut b_chunks = b.chunks_exact(CHUNK);
+    let mut sum: F = a_chunks
+        .by_ref()
+        .zip(b_chunks.by_ref())
+        .map(|(a, b)| {
+            let a: &[F; CHUNK] = a.try_into().expect("exact chunk");
+            let b: &[F; CHUNK] = b.try_into().expect("exact chunk");
+            F::sum_of_products(a, b)
+        })
+        .sum();
+    for (x, y) in a_chunks.remainder().iter().zip(b_chunks.remainder()) {
+        sum += *x * y;
+    }
+    Ok(sum)
+}
+
+// a_i + b_i, e.g. for summing quotients held in evaluation form.
+pub fn add_slices<F: PrimeField>(a: &[F], b: &[F]) -> Result<Vec<F>, FieldError> {
+    check_lengths(a, b)?;
+    Ok(a.iter().zip(b).map(|(x, y)| *x + y).collect())
+}
+
+// a_i · b_i, the pointwise product between a forward and an inverse NTT.
+pub fn mul_slices<F: PrimeField>(a: &[F], b: &[F]) -> Result<Vec<F>, FieldError> {
+    check_lengths(a, b)?;
+    Ok(a.iter().zip(b).map(|(x, y)| *x * y).collect())
+}
+
+fn check_lengths<F>(a: &[F], b: &[F]) -> Result<(), FieldError> {
+    match a.len() == b.len() {
+        true => Ok(()),
+        false => Err(FieldError::LengthMismatch {
+            left: a.len(),
+            right: b.len(),
+        }),
+    }
+}
+
+// Π bases_i^exps_i by simultaneous square-and-multiply: one shared chain of
+// squarings, multiplying in every base whose exponent has the current bit set.
+pub fn multi_pow<F: PrimeField>(bases: &[F], exps: &[u128]) -> Result<F, FieldError> {
+    if bases.len() != exps.len() {
+        return Err(FieldError::LengthMismatch {
+            left: bases.len(),
+            right: exps.len(),
+        });
+    }
+
+    let bits = exps
+        .iter()
+        .map(|e| 128 - e.leading_zeros())
+        .max()
+        .unwrap_or(0);
+    let mut acc = F::one();
+    for bit in (0..bits).rev() {
+        acc.square_in_place();
+        for (base, exp) in bases.iter().zip(exps) {
+            if (exp >> bit) & 1 == 1 {
+                acc *= base;
+            }
+        }
+    }
+    Ok(acc)
+}
+
+// `group_order_factorization` lists (prime, exponent) pairs whose product is p - 1.
+pub fn multiplicative_order<F: PrimeField>(e: F, group_order_factorization: &[(u64, u32)]) -> u64 {
+    assert!(!e.is_zero(), "zero has no multiplicative order");
+
+    let mut order: u64 = group_order_factorization
+        .iter()
+        .map(|&(prime, exp)| prime.pow(exp))
+        .product();
+
+    for &(prime, exp) in group_order_factorization {
+        for _ in 0..exp {
+            if !e.pow([order / prime]).is_one() {
+                break;
+            }
+            order /= prime;
+        }
+    }
+
+    order
+}
+
+// x^exponent, with the exponent reduced modulo p - 1 first (Fermat). x must be nonzero.
+pub fn pow_mod_order<F: PrimeField>(x: F, exponent: u128) -> F {
+    assert!(!x.is_zero(), "base must be nonzero");
+    let exponent = match group_order_u128::<F>() {
+        Some(order) => exponent % order,
+        None => exponent,
+    };
+    x.pow([exponent as u64, (exponent >> 64) as u64])
+}
+
+// Whether some element has multiplicative order exactly n, i.e. n divides p - 1.
+pub fn has_nth_root_of_unity<F: PrimeField>(n: u64) -> bool {
+    if n == 0 {
+        return false;
+    }
+    // p is odd, so p - 1 only touches the lowest limb
+    let modulus = F::MODULUS;
+    let limbs = modulus.as_ref();
+    let rem = limbs
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(0u128, |rem, (i, &limb)| {
+            let limb = if i == 0 { limb - 1 } else { limb };
+            ((rem << 64) | limb as u128) % n as u128
+        });
+    rem == 0
+}
+
+// Whether n is a power of two within the two-adic subgroup, the sizes the radix-2
+// FFT and NttDomain accept.
+pub fn has_two_adic_root_of_unity<F: FftField>(n: u64) -> bool {
+    n.is_power_of_two() && n.ilog2() <= F::TWO_ADICITY
+}
+
+// p - 1, when it fits in 128 bits
+fn group_order_u128<F: PrimeField>() -> Option<u128> {
+    let modulus = F::MODULUS;
+    let limbs = modulus.as_ref();
+    if limbs.iter().skip(2).any(|&limb| limb != 0) {
+        return None;
+    }
+    let low = limbs[0] as u128;
+    let high = limbs.get(1).copied().unwrap_or(0) as u128;
+    Some((low | high << 64) - 1)
+}
+
+// `a` when choice is false, `b` when it is true, as a branch-free blend of the
+// canonical limbs. Not a constant-time guarantee: the conversions to and from
+// the canonical form are not written to be constant-time.
+pub fn conditional_select<F: PrimeField>(a: &F, b: &F, choice: bool) -> F {
+    let mask = (choice as u64).wrapping_neg();
+    let mut out = a.into_bigint();
+    for (x, y) in out.as_mut().iter_mut().zip(b.into_bigint().as_ref()) {
+        *x ^= mask & (*x ^ y);
+    }
+    F::from_bigint(out).expect("blend of two canonical elements is canonical")
+}
+
+// Equality that inspects every limb rather than stopping at the first difference.
+pub fn ct_eq<F: PrimeField>(a: &F, b: &F) -> bool {
+    let diff = a
+        .into_bigint()
+        .as_ref()
+        .iter()
+        .zip(b.into_bigint().as_ref())
+        .fold(0u64, |acc, (x, y)| acc | (x ^ y));
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{FftField, Field, Zero};
+
+    use super::*;
+    use crate::field::Fq;
+
+    // p - 1 = 2^32 * 3 * 5 * 17 * 257 * 65537
+    const GOLDILOCKS_ORDER_FACTORS: [(u64, u32); 6] =
+        [(2, 32), (3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)];
+
+    #[test]
+    fn test_multiplicative_order_generator() {
+        assert_eq!(
+            multiplicative_order(Fq::GENERATOR, &GOLDILOCKS_ORDER_FACTORS),
+            18446744069414584320
+        );
+        assert_eq!(
+            multiplicative_order(Fq::from(1u64), &GOLDILOCKS_ORDER_FACTORS),
+            1
+        );
+        assert_eq!(
+            multiplicative_order(-Fq::from(1u64), &GOLDILOCKS_ORDER_FACTORS),
+            2
+        );
+    }
+
+    #[test]
+    fn test_has_nth_root_of_unity() {
+        for n in [1u64, 2, 3, 6, 15, 257, 65537, 1 << 32, 18446744069414584320] {
+            assert!(has_nth_root_of_unity::<Fq>(n), "{n}");
+        }
+        for n in [0u64, 7, 9, 1 << 33, 18446744069414584321] {
+            assert!(!has_nth_root_of_unity::<Fq>(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_has_two_adic_root_of_unity() {
+        for n in [1u64, 2, 1 << 20, 1 << 32] {
+            assert!(has_two_adic_root_of_unity::<Fq>(n));
+            assert!(Fq::get_root_of_unity(n).is_some());
+        }
+        // 3 divides p - 1 but is not a radix-2 size
+        for n in [0u64, 3, 12, 1 << 33] {
+            assert!(!has_two_adic_root_of_unity::<Fq>(n));
+        }
+    }
+
+    #[test]
+    fn test_multiplicative_order_root_of_unity() {
+        for n in [4u64, 16, 1 << 20] {
+            let root = Fq::get_root_of_unity(n).unwrap();
+            assert_eq!(multiplicative_order(root, &GOLDILOCKS_ORDER_FACTORS), n);
+        }
+
+        let root = Fq::GENERATOR.pow([18446744069414584320 / 15]);
+        assert_eq!(multiplicative_order(root, &GOLDILOCKS_ORDER_FACTORS), 15);
+    }
+
+    #[test]
+    fn test_pow_mod_order() {
+        let p_minus_one = 18446744069414584320u128;
+        for x in [1u64, 2, 7, 123456789] {
+            let x = Fq::from(x);
+            assert_eq!(pow_mod_order(x, p_minus_one), Fq::from(1u64));
+            assert_eq!(pow_mod_order(x, p_minus_one + 5), x.pow([5]));
+            assert_eq!(pow_mod_order(x, u128::MAX), x.pow([u64::MAX, u64::MAX]));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "base must be nonzero")]
+    fn test_pow_mod_order_zero_base() {
+        pow_mod_order(Fq::zero(), 3);
+    }
+
+    #[test]
+    fn test_checked_inv() {
+        let x = Fq::from(7u64);
+        assert_eq!(checked_inv(x).unwrap() * x, Fq::from(1u64));
+        assert_eq!(checked_inv(Fq::zero()), Err(FieldError::NotInvertible));
+    }
+
+    #[test]
+    fn test_inv_fermat_matches_checked_inv() {
+        use ark_std::UniformRand;
+
+        let rng = &mut ark_std::test_rng();
+        for x in (0..200)
+            .map(|_| Fq::rand(rng))
+            .chain([Fq::from(1u64), -Fq::from(1u64)])
+        {
+            if x.is_zero() {
+                continue;
+            }
+            assert_eq!(inv_fermat(x), checked_inv(x));
+        }
+        assert_eq!(inv_fermat(Fq::zero()), Err(FieldError::NotInvertible));
+    }
+
+    #[test]
+    fn test_checked_div() {
+        assert_eq!(
+            checked_div(Fq::from(21u64), Fq::from(7u64)).unwrap(),
+            Fq::from(3u64)
+        );
+        assert_eq!(
+            checked_div(Fq::from(1u64), Fq::zero()),
+            Err(FieldError::NotInvertible)
+        );
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        let a = Fq::from(12345);
+        let b = -Fq::from(1);
+
+        assert_eq!(conditional_select(&a, &b, false), a);
+        assert_eq!(conditional_select(&a, &b, true), b);
+        assert_eq!(conditional_select(&b, &b, true), b);
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let a = Fq::from(7);
+        assert!(ct_eq(&a, &Fq::from(7)));
+        assert!(!ct_eq(&a, &Fq::from(8)));
+        assert!(ct_eq(&Fq::zero(), &(a - a)));
+    }
+
+    #[test]
+    fn test_sum_of_products() {
+        for len in [0, 3, 8, 21] {
+            let a: Vec<Fq> = (0..len).map(|i| -Fq::from(i as u64 * 31 + 1)).collect();
+            let b: Vec<Fq> = (0..len)
+                .map(|i| Fq::from(i as u64 * i as u64 + 5))
+                .collect();
+            let naive = a
+                .iter()
+                .zip(&b)
+                .fold(Fq::zero(), |acc, (x, y)| acc + *x * y);
+            assert_eq!(sum_of_products(&a, &b), Ok(naive));
+        }
+
+        assert_eq!(
+            sum_of_products(&[Fq::from(1u64); 3], &[Fq::from(1u64); 2]),
+            Err(FieldError::LengthMismatch { left: 3, right: 2 })
+        );
+    }
+
+    #[test]
+    fn test_add_and_mul_slices() {
+        let a: Vec<Fq> = (0..13u64).map(|i| -Fq::from(i * 7 + 1)).collect();
+        let b: Vec<Fq> = (0..13u64).map(|i| Fq::from(i * i + 3)).collect();
+
+        let sums = add_slices(&a, &b).unwrap();
+        let products = mul_slices(&a, &b).unwrap();
+        for i in 0..a.len() {
+            assert_eq!(sums[i], a[i] + b[i]);
+            assert_eq!(products[i], a[i] * b[i]);
+        }
+        assert_eq!(
+            products.iter().fold(Fq::zero(), |acc, p| acc + p),
+            sum_of_products(&a, &b).unwrap()
+        );
+        assert_eq!(add_slices::<Fq>(&[], &[]), Ok(vec![]));
+
+        assert_eq!(
+            add_slices(&a, &b[..4]),
+            Err(FieldError::LengthMismatch { left: 13, right: 4 })
+        );
+        assert_eq!(
+            mul_slices(&a[..2], &b),
+            Err(FieldError::LengthMismatch { left: 2, right: 13 })
+        );
+    }
+
+    #[test]
+    fn test_multi_pow() {
+        let bases = [3u64, 5, 7, 11].map(Fq::from);
+        let exps = [0u128, 1, u64::MAX as u128 + 12345, 1 << 100];
+        let naive: Fq = bases
+            .iter()
+            .zip(exps)
+            .map(|(b, e)| b.pow([e as u64, (e >> 64) as u64]))
+            .product();
+
+        assert_eq!(multi_pow(&bases, &exps), Ok(naive));
+        assert_eq!(multi_pow::<Fq>(&[], &[]), Ok(Fq::from(1u64)));
+        assert_eq!(
+            multi_pow(&bases, &exps[..2]),
+            Err(FieldError::LengthMismatch { left: 4, right: 2 })
+        );
+    }
+}
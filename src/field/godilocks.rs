@@ -5,4 +5,145 @@ use ark_ff::fields::{Fp64, MontBackend, MontConfig};
 #[generator = "7"]
 #[allow(non_local_definitions)]
 pub struct FqConfig;
+
+/// Goldilocks field, `p = 2^64 - 2^32 + 1`.
+///
+/// Small integers convert directly and are reduced modulo `p`:
+///
+/// ```
+/// use stark::field::Fq;
+///
+/// assert_eq!(Fq::from(5u64) + Fq::from(7u8), Fq::from(12u32));
+/// assert_eq!(Fq::from(18446744069414584322u128), Fq::from(1u64));
+/// assert_eq!(Fq::from(-1i64), -Fq::from(1u64));
+/// assert_eq!(Fq::from(true), Fq::from(1u64));
+/// ```
 pub type Fq = Fp64<MontBackend<FqConfig, 1>>;
+
+const MODULUS: u64 = 0xffff_ffff_0000_0001;
+// 2^64 mod p
+const EPSILON: u64 = 0xffff_ffff;
+
+// Canonical x mod p without a division. With x = hi·2^96 + mid·2^64 + lo and
+// 2^64 ≡ 2^32 - 1, 2^96 ≡ -1, this is lo - hi + mid·(2^32 - 1).
+pub fn reduce_u128(x: u128) -> u64 {
+    let lo = x as u64;
+    let hi = (x >> 96) as u64;
+    let mid = ((x >> 64) as u64) & EPSILON;
+
+    let (mut t, borrow) = lo.overflowing_sub(hi);
+    if borrow {
+        t = t.wrapping_sub(EPSILON);
+    }
+    let (mut t, carry) = t.overflowing_add(mid * EPSILON);
+    if carry {
+        t = t.wrapping_add(EPSILON);
+    }
+    if t >= MODULUS { t - MODULUS } else { t }
+}
+
+// Multiplies canonical u64 representatives straight, outside Montgomery form.
+pub fn mul_canonical(a: u64, b: u64) -> u64 {
+    reduce_u128(a as u128 * b as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{FftField, Field, PrimeField};
+
+    use super::*;
+
+    #[test]
+    fn test_ordering_matches_canonical_value() {
+        // Fp orders by canonical value, not by its Montgomery representation
+        let mut elements: Vec<Fq> = [5u64, 1, 18446744069414584320, 0, 42, 7]
+            .into_iter()
+            .map(Fq::from)
+            .collect();
+        elements.sort();
+
+        let values: Vec<u64> = elements
+            .iter()
+            .map(|x| x.into_bigint().as_ref()[0])
+            .collect();
+        assert_eq!(values, vec![0, 1, 5, 7, 42, 18446744069414584320]);
+        assert!(-Fq::from(1) > Fq::from(1));
+    }
+
+    #[test]
+    fn test_uniform_rand_is_unbiased() {
+        use ark_std::UniformRand;
+
+        // UniformRand rejects draws >= p instead of reducing them, so the 16
+        // top-value buckets should be hit evenly
+        let rng = &mut ark_std::test_rng();
+        let draws = 16_000;
+        let mut buckets = [0u32; 16];
+        let bucket_width = Fq::MODULUS.as_ref()[0] / 16 + 1;
+        for _ in 0..draws {
+            let value = Fq::rand(rng).into_bigint().as_ref()[0];
+            buckets[(value / bucket_width) as usize] += 1;
+        }
+
+        let expected = draws as f64 / 16.0;
+        let chi_squared: f64 = buckets
+            .iter()
+            .map(|&b| (b as f64 - expected).powi(2) / expected)
+            .sum();
+        // 15 degrees of freedom: P(chi^2 > 37.7) < 0.001
+        assert!(chi_squared < 37.7, "chi^2 = {chi_squared}");
+    }
+
+    #[test]
+    fn test_reduce_u128_matches_reference() {
+        let p = MODULUS as u128;
+        let edges = [
+            0,
+            1,
+            2,
+            EPSILON,
+            1 << 32,
+            MODULUS - 2,
+            MODULUS - 1,
+            MODULUS,
+            u64::MAX,
+        ];
+        for a in edges {
+            for b in edges {
+                let x = a as u128 * b as u128;
+                assert_eq!(reduce_u128(x) as u128, x % p, "{a} * {b}");
+            }
+        }
+        assert_eq!(reduce_u128(u128::MAX) as u128, u128::MAX % p);
+
+        let mut x = 0x0123_4567_89ab_cdef_u128;
+        for _ in 0..1000 {
+            x = x
+                .wrapping_mul(0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c835)
+                .wrapping_add(1);
+            assert_eq!(reduce_u128(x) as u128, x % p);
+        }
+    }
+
+    #[test]
+    fn test_mul_canonical_matches_fq() {
+        for (a, b) in [
+            (MODULUS - 1, MODULUS - 1),
+            (MODULUS - 1, 2),
+            (1 << 63, 1 << 40),
+        ] {
+            let expected = (Fq::from(a) * Fq::from(b)).into_bigint().as_ref()[0];
+            assert_eq!(mul_canonical(a, b), expected);
+        }
+    }
+
+    #[test]
+    fn test_generator_and_two_adic_root() {
+        assert_eq!(Fq::GENERATOR, Fq::from(7));
+        assert_eq!(Fq::TWO_ADICITY, 32);
+
+        let root = Fq::TWO_ADIC_ROOT_OF_UNITY;
+        assert_eq!(root.pow([1u64 << 32]), Fq::from(1));
+        assert_ne!(root.pow([1u64 << 31]), Fq::from(1));
+    }
+}
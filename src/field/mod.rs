@@ -1,3 +1,6 @@
+pub mod arithmetic;
 pub mod godilocks;
+#[cfg(test)]
+pub mod test_fields;
 
 pub use godilocks::Fq;
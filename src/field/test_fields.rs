@@ -0,0 +1,23 @@
+use ark_ff::fields::{Fp64, MontBackend, MontConfig};
+
+// Prime fields besides Goldilocks, for checking that generic code does not
+// quietly depend on Fq.
+
+// p = 15·2^27 + 1, with a two-adic subgroup of order 2^27.
+#[derive(MontConfig)]
+#[modulus = "2013265921"]
+#[generator = "31"]
+#[allow(non_local_definitions)]
+pub struct BabyBearConfig;
+
+pub type BabyBear = Fp64<MontBackend<BabyBearConfig, 1>>;
+
+// p = 2^61 - 1. p - 1 = 2·(2^60 - 1), so the only two-adic subgroup has order 2
+// and no NTT larger than that exists.
+#[derive(MontConfig)]
+#[modulus = "2305843009213693951"]
+#[generator = "37"]
+#[allow(non_local_definitions)]
+pub struct Mersenne61Config;
+
+pub type Mersenne61 = Fp64<MontBackend<Mersenne61Config, 1>>;
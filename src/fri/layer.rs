@@ -1,9 +1,11 @@
 use ark_ff::PrimeField;
-use ark_poly::{
-    EvaluationDomain, GeneralEvaluationDomain, Polynomial, univariate::DensePolynomial,
-};
+use ark_poly::{EvaluationDomain, GeneralEvaluationDomain, univariate::DensePolynomial};
+use ark_std::vec::Vec;
 
-use crate::{crypto::merkle::MerkleTree, polynomial::domain};
+use crate::{
+    crypto::merkle::MerkleTree,
+    polynomial::{domain, evaluate_points},
+};
 
 pub struct FriLayer<F: PrimeField> {
     pub evaluations: Vec<F>,
@@ -14,13 +16,11 @@ pub struct FriLayer<F: PrimeField> {
 impl<F: PrimeField> FriLayer<F> {
     pub fn from_poly(poly: &DensePolynomial<F>, coset: F, domain_size: usize) -> Self {
         let domain: GeneralEvaluationDomain<F> = domain(domain_size);
-        let evaluations = domain
+        let points = domain
             .elements()
-            .map(|root| {
-                let cur1 = root * coset;
-                poly.evaluate(&cur1)
-            })
+            .map(|root| root * coset)
             .collect::<Vec<_>>();
+        let evaluations = evaluate_points(poly, &points);
         let merkle_tree = MerkleTree::new(evaluations.clone());
 
         Self {
@@ -2,14 +2,30 @@ pub mod layer;
 pub mod prover;
 pub mod verifier;
 
+use ark_ff::PrimeField;
+use ark_poly::{Polynomial, univariate::DensePolynomial};
+
+// Whether a polynomial recovered from a codeword, e.g. by interpolating a
+// layer, stays within a degree bound. The zero polynomial passes any bound.
+pub fn check_degree_bound<F: PrimeField>(poly: &DensePolynomial<F>, max_degree: usize) -> bool {
+    poly.degree() <= max_degree
+}
+
 #[cfg(test)]
 mod tests {
     use ark_ff::Zero;
     use ark_poly::{DenseUVPolynomial, univariate::DensePolynomial};
 
+    use super::check_degree_bound;
     use crate::crypto::transcript::Transcript;
     use crate::field::Fq;
-    use crate::fri::{prover::generate_proof, verifier::verify};
+    use crate::fri::{
+        prover::{
+            estimate_proof_size, estimate_proof_size_with_folding, generate_proof,
+            generate_proof_with_folding,
+        },
+        verifier::{verify, verify_with_blowup},
+    };
 
     #[test]
     fn test_fri_roundtrip_degree_3() {
@@ -58,4 +74,180 @@ mod tests {
         let mut verifier_transcript = Transcript::new(Fq::zero());
         assert!(verify(&proof, &mut verifier_transcript).is_err());
     }
+
+    #[test]
+    fn test_fri_zero_polynomial() {
+        let mut prover_transcript = Transcript::new(Fq::zero());
+        let proof = generate_proof(DensePolynomial::zero(), 2, 2, &mut prover_transcript);
+        assert!(proof.layers_root.is_empty());
+        assert_eq!(proof.const_val, Fq::zero());
+
+        let mut verifier_transcript = Transcript::new(Fq::zero());
+        assert!(verify(&proof, &mut verifier_transcript).is_ok());
+    }
+
+    #[test]
+    fn test_estimate_proof_size() {
+        use ark_serialize::CanonicalSerialize;
+
+        for (degree, blowup, queries) in [(0, 2, 2), (3, 2, 4), (13, 4, 16), (64, 8, 3)] {
+            let coeffs = (0..=degree as u64).map(|i| Fq::from(i + 1)).collect();
+            let poly = DensePolynomial::from_coefficients_vec(coeffs);
+            let mut transcript = Transcript::new(Fq::zero());
+            let proof = generate_proof(poly, blowup, queries, &mut transcript);
+
+            assert_eq!(
+                estimate_proof_size::<Fq>(degree, blowup, queries),
+                proof.compressed_size()
+            );
+        }
+    }
+
+    #[test]
+    fn test_fri_folding_factors() {
+        let coeffs = (0..21u64).map(|i| Fq::from(i * i + 1)).collect();
+        let poly = DensePolynomial::from_coefficients_vec(coeffs);
+
+        for folding_factor in [2, 4] {
+            let mut prover_transcript = Transcript::new(Fq::zero());
+            let proof = generate_proof_with_folding(
+                poly.clone(),
+                2,
+                4,
+                folding_factor,
+                &mut prover_transcript,
+            );
+            assert_eq!(proof.folding_factor, folding_factor);
+
+            let mut verifier_transcript = Transcript::new(Fq::zero());
+            assert!(verify(&proof, &mut verifier_transcript).is_ok());
+        }
+
+        // degree 20 folds in 5 rounds 2-way but only 3 rounds 4-way
+        let mut transcript = Transcript::new(Fq::zero());
+        let proof = generate_proof_with_folding(poly, 2, 4, 4, &mut transcript);
+        assert_eq!(proof.layers_root.len(), 3);
+    }
+
+    #[test]
+    fn test_fri_folding_factor_four_soundness() {
+        let coeffs = (0..16u64).map(Fq::from).collect();
+        let poly = DensePolynomial::from_coefficients_vec(coeffs);
+        let mut prover_transcript = Transcript::new(Fq::zero());
+        let proof = generate_proof_with_folding(poly, 2, 2, 4, &mut prover_transcript);
+
+        let mut tampered = proof.clone();
+        tampered.const_val += Fq::from(1);
+        assert!(verify(&tampered, &mut Transcript::new(Fq::zero())).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.decommitment_list[0].evaluations[0][1] += Fq::from(1);
+        assert!(verify(&tampered, &mut Transcript::new(Fq::zero())).is_err());
+
+        let mut tampered = proof;
+        tampered.folding_factor = 3;
+        assert!(verify(&tampered, &mut Transcript::new(Fq::zero())).is_err());
+    }
+
+    #[test]
+    fn test_estimate_proof_size_with_folding() {
+        use ark_serialize::CanonicalSerialize;
+
+        for (degree, folding_factor) in [(0, 4), (3, 4), (17, 4), (40, 8)] {
+            let coeffs = (0..=degree as u64).map(|i| Fq::from(i + 1)).collect();
+            let poly = DensePolynomial::from_coefficients_vec(coeffs);
+            let mut transcript = Transcript::new(Fq::zero());
+            let proof = generate_proof_with_folding(poly, 2, 3, folding_factor, &mut transcript);
+
+            assert_eq!(
+                estimate_proof_size_with_folding::<Fq>(degree, 2, 3, folding_factor),
+                proof.compressed_size()
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_degree_bound() {
+        use ark_poly::EvaluationDomain;
+
+        use crate::fri::layer::FriLayer;
+        use crate::polynomial::{domain, fast_interpolate};
+
+        // the first layer of a FRI proof for a degree-3 polynomial
+        let poly = DensePolynomial::from_coefficients_vec((1..=4u64).map(Fq::from).collect());
+        let mut transcript = Transcript::new(Fq::zero());
+        let proof = generate_proof(poly.clone(), 4, 2, &mut transcript);
+        let layer = FriLayer::from_poly(&poly, proof.coset, proof.domain_size);
+        assert_eq!(layer.merkle_tree.root(), proof.layers_root[0]);
+        let domain = domain::<Fq>(proof.domain_size)
+            .get_coset(proof.coset)
+            .unwrap();
+        let mut codeword = layer.evaluations.clone();
+
+        let recovered = fast_interpolate(codeword.clone(), &domain);
+        assert_eq!(recovered, poly);
+        assert!(check_degree_bound(&recovered, 3));
+        assert!(!check_degree_bound(&recovered, 2));
+        assert!(check_degree_bound(&DensePolynomial::<Fq>::zero(), 0));
+
+        // one corrupted evaluation pushes the interpolant far past the bound
+        codeword[5] += Fq::from(1);
+        let tampered = fast_interpolate(codeword, &domain);
+        assert!(!check_degree_bound(&tampered, 3));
+    }
+
+    #[test]
+    fn test_fri_verify_with_blowup() {
+        let poly = DensePolynomial::from_coefficients_vec((1..=6u64).map(Fq::from).collect());
+
+        for folding_factor in [2, 4] {
+            let mut prover_transcript = Transcript::new(Fq::zero());
+            let proof = generate_proof_with_folding(
+                poly.clone(),
+                4,
+                2,
+                folding_factor,
+                &mut prover_transcript,
+            );
+
+            assert!(verify_with_blowup(&proof, 4, &mut Transcript::new(Fq::zero())).is_ok());
+            assert!(verify_with_blowup(&proof, 2, &mut Transcript::new(Fq::zero())).is_ok());
+            // the domain is too small to fold this many times at a higher rate
+            assert!(verify_with_blowup(&proof, 8, &mut Transcript::new(Fq::zero())).is_err());
+            assert!(verify_with_blowup(&proof, 0, &mut Transcript::new(Fq::zero())).is_err());
+        }
+    }
+
+    #[test]
+    fn test_fri_rejects_openings_from_another_tree() {
+        use crate::crypto::merkle::MerkleTree;
+        use crate::fri::layer::FriLayer;
+
+        let poly = DensePolynomial::from_coefficients_vec((1..=8u64).map(Fq::from).collect());
+        let mut prover_transcript = Transcript::new(Fq::zero());
+        let mut proof = generate_proof(poly.clone(), 2, 2, &mut prover_transcript);
+
+        // a tree that agrees with layer 0 on every opened leaf but has another root
+        let layer = FriLayer::from_poly(&poly, proof.coset, proof.domain_size);
+        let opened: Vec<usize> = proof
+            .decommitment_list
+            .iter()
+            .flat_map(|d| d.auth_paths[0].iter().map(|p| p.index))
+            .collect();
+        let untouched = (0..proof.domain_size)
+            .find(|i| !opened.contains(i))
+            .unwrap();
+        let mut evaluations = layer.evaluations.clone();
+        evaluations[untouched] += Fq::from(1);
+        let forged = MerkleTree::new(evaluations);
+
+        for decommitment in proof.decommitment_list.iter_mut() {
+            for path in decommitment.auth_paths[0].iter_mut() {
+                *path = forged.generate_proof(path.index);
+            }
+        }
+
+        let mut verifier_transcript = Transcript::new(Fq::zero());
+        assert!(verify(&proof, &mut verifier_transcript).is_err());
+    }
 }
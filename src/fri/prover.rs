@@ -1,38 +1,112 @@
-use std::ops::Mul;
-
 use ark_ff::PrimeField;
 use ark_poly::{DenseUVPolynomial, Polynomial, univariate::DensePolynomial};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+};
+use ark_std::{iter::successors, vec, vec::Vec};
 
 use crate::{
     crypto::{merkle::MerkleProof, transcript::Transcript},
-    fri::layer::FriLayer,
+    fri::{check_degree_bound, layer::FriLayer},
+    polynomial::{constant_value, is_constant},
+    serialize::{read_vec, read_vec_with},
 };
 
-#[derive(Clone, Debug, Default)]
+// For every layer, the `folding_factor` evaluations on the coset x·ζ^j the
+// query lands in, where ζ is a primitive folding_factor-th root of unity.
+#[derive(Clone, Debug, Default, CanonicalSerialize)]
 pub struct Decommitment<F: PrimeField> {
-    pub evaluations: Vec<F>,
-    pub auth_paths: Vec<MerkleProof<F>>,
-    pub sym_evaluations: Vec<F>,
-    pub sym_auth_paths: Vec<MerkleProof<F>>,
+    pub evaluations: Vec<Vec<F>>,
+    pub auth_paths: Vec<Vec<MerkleProof<F>>>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, CanonicalSerialize)]
 pub struct FriProof<F: PrimeField> {
     pub domain_size: usize,
     pub coset: F,
     pub number_of_queries: usize,
+    pub folding_factor: usize,
     pub layers_root: Vec<F>,
     pub const_val: F,
     pub decommitment_list: Vec<Decommitment<F>>,
 }
 
+// Vectors are decoded with read_vec.
+impl<F: PrimeField> Valid for Decommitment<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.evaluations.check()?;
+        self.auth_paths.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for Decommitment<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self {
+            evaluations: read_vec_with(&mut reader, compress, validate, |r| {
+                read_vec(r, compress, validate)
+            })?,
+            auth_paths: read_vec_with(&mut reader, compress, validate, |r| {
+                read_vec(r, compress, validate)
+            })?,
+        })
+    }
+}
+
+impl<F: PrimeField> Valid for FriProof<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.coset.check()?;
+        self.layers_root.check()?;
+        self.const_val.check()?;
+        self.decommitment_list.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for FriProof<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self {
+            domain_size: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            coset: F::deserialize_with_mode(&mut reader, compress, validate)?,
+            number_of_queries: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            folding_factor: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            layers_root: read_vec(&mut reader, compress, validate)?,
+            const_val: F::deserialize_with_mode(&mut reader, compress, validate)?,
+            decommitment_list: read_vec(&mut reader, compress, validate)?,
+        })
+    }
+}
+
 pub fn generate_proof<F: PrimeField>(
     poly: DensePolynomial<F>,
     blowup_factor: usize,
     number_of_queries: usize,
     transcript: &mut Transcript<F>,
 ) -> FriProof<F> {
-    let domain_size = (poly.degree() + 1).next_power_of_two() * blowup_factor;
+    generate_proof_with_folding(poly, blowup_factor, number_of_queries, 2, transcript)
+}
+
+// Folds `folding_factor` ways per round, so a degree-d polynomial takes
+// log_k(d) rounds instead of log_2(d).
+pub fn generate_proof_with_folding<F: PrimeField>(
+    poly: DensePolynomial<F>,
+    blowup_factor: usize,
+    number_of_queries: usize,
+    folding_factor: usize,
+    transcript: &mut Transcript<F>,
+) -> FriProof<F> {
+    assert!(
+        folding_factor >= 2 && folding_factor.is_power_of_two(),
+        "folding factor must be a power of two"
+    );
+    let num_layers = num_folds(poly.degree(), folding_factor);
+    let domain_size = folding_factor.pow(num_layers as u32) * blowup_factor;
     let coset = F::GENERATOR;
 
     let mut layers = Vec::new();
@@ -40,8 +114,15 @@ pub fn generate_proof<F: PrimeField>(
     let mut curr_poly = poly.clone();
     let mut curr_coset = coset;
     let mut curr_domain_size = domain_size;
+    // the rate allows degree below domain_size / blowup_factor, and every fold
+    // divides that bound by the folding factor
+    let mut degree_bound = domain_size / blowup_factor - 1;
+    assert!(
+        check_degree_bound(&curr_poly, degree_bound),
+        "polynomial exceeds the degree bound of the domain"
+    );
 
-    while curr_poly.degree() != 0 {
+    while !is_constant(&curr_poly) {
         let layer = FriLayer::from_poly(&curr_poly, curr_coset, curr_domain_size);
         let root = layer.merkle_tree.root();
 
@@ -50,12 +131,17 @@ pub fn generate_proof<F: PrimeField>(
         transcript.digest(root);
 
         let random_r = transcript.generate_a_challenge();
-        curr_poly = fold_polynomial(&curr_poly, random_r);
-        curr_coset = curr_coset.square();
-        curr_domain_size /= 2;
+        curr_poly = fold_polynomial_by(&curr_poly, random_r, folding_factor);
+        degree_bound /= folding_factor;
+        assert!(
+            check_degree_bound(&curr_poly, degree_bound),
+            "folded layer exceeds its degree bound"
+        );
+        curr_coset = curr_coset.pow([folding_factor as u64]);
+        curr_domain_size /= folding_factor;
     }
 
-    let const_val = curr_poly.coeffs[0];
+    let const_val = constant_value(&curr_poly).expect("folding stops at a constant");
     transcript.digest(const_val);
 
     let query_indices = transcript.generate_challenge_list_usize(number_of_queries, domain_size);
@@ -65,19 +151,18 @@ pub fn generate_proof<F: PrimeField>(
         let mut curr_idx = query_idx;
 
         layers.iter().for_each(|layer| {
-            let sym_idx = (curr_idx + layer.domain_size / 2) % layer.domain_size;
-            decommitment.evaluations.push(layer.evaluations[curr_idx]);
-            decommitment
-                .auth_paths
-                .push(layer.merkle_tree.generate_proof(curr_idx));
-            decommitment
-                .sym_evaluations
-                .push(layer.evaluations[sym_idx]);
-            decommitment
-                .sym_auth_paths
-                .push(layer.merkle_tree.generate_proof(sym_idx));
+            let stride = layer.domain_size / folding_factor;
+            curr_idx %= stride;
+            let indices = (0..folding_factor).map(|j| curr_idx + j * stride);
 
-            curr_idx %= layer.domain_size / 2;
+            decommitment
+                .evaluations
+                .push(indices.clone().map(|i| layer.evaluations[i]).collect());
+            decommitment.auth_paths.push(
+                indices
+                    .map(|i| layer.merkle_tree.generate_proof(i))
+                    .collect(),
+            );
         });
 
         decommitment_list.push(decommitment);
@@ -87,26 +172,84 @@ pub fn generate_proof<F: PrimeField>(
         domain_size,
         coset,
         number_of_queries,
+        folding_factor,
         layers_root,
         const_val,
         decommitment_list,
     }
 }
 
+// Rounds of k-way folding until a degree-d polynomial is constant.
+fn num_folds(mut degree: usize, folding_factor: usize) -> usize {
+    let mut folds = 0;
+    while degree > 0 {
+        degree /= folding_factor;
+        folds += 1;
+    }
+    folds
+}
+
+// Serialized size of the proof generate_proof would produce for a polynomial of
+// this degree, computed from the parameters alone. Every element has a fixed
+// width, so the estimate is exact.
+pub fn estimate_proof_size<F: PrimeField>(
+    degree: usize,
+    blowup_factor: usize,
+    number_of_queries: usize,
+) -> usize {
+    estimate_proof_size_with_folding::<F>(degree, blowup_factor, number_of_queries, 2)
+}
+
+pub fn estimate_proof_size_with_folding<F: PrimeField>(
+    degree: usize,
+    blowup_factor: usize,
+    number_of_queries: usize,
+    folding_factor: usize,
+) -> usize {
+    let word = 0usize.compressed_size();
+    let elem = F::zero().compressed_size();
+
+    let num_layers = num_folds(degree, folding_factor);
+    let log_k = folding_factor.ilog2() as usize;
+    let domain_size = folding_factor.pow(num_layers as u32) * blowup_factor;
+    let log_domain = domain_size.ilog2() as usize;
+
+    // index, leaf, auth path (length prefix + one node per level), root
+    let merkle_proofs: usize = (0..num_layers)
+        .map(|i| {
+            word + folding_factor * (word + elem + word + (log_domain - i * log_k) * elem + elem)
+        })
+        .sum();
+    let evaluations = num_layers * (word + folding_factor * elem);
+    let decommitment = 2 * word + evaluations + merkle_proofs;
+
+    let params = 3 * word + elem;
+    let layers_root = word + num_layers * elem;
+    let const_val = elem;
+    let decommitments = word + number_of_queries * decommitment;
+
+    params + layers_root + const_val + decommitments
+}
+
 pub fn fold_polynomial<F: PrimeField>(poly: &DensePolynomial<F>, r: F) -> DensePolynomial<F> {
-    let coeffs = poly.coeffs.clone();
-    let even_coeffs = coeffs.iter().step_by(2).cloned().collect::<Vec<_>>();
-    let odd_coeffs = coeffs
-        .iter()
-        .skip(1)
-        .step_by(2)
-        .cloned()
-        .collect::<Vec<_>>();
-
-    let even_poly = DensePolynomial::from_coefficients_vec(even_coeffs);
-    let odd_poly = DensePolynomial::from_coefficients_vec(odd_coeffs);
-
-    even_poly + odd_poly.mul(r)
+    fold_polynomial_by(poly, r, 2)
+}
+
+// Splits f(x) = sum_j x^j·f_j(x^k) and returns sum_j r^j·f_j(x).
+pub fn fold_polynomial_by<F: PrimeField>(
+    poly: &DensePolynomial<F>,
+    r: F,
+    folding_factor: usize,
+) -> DensePolynomial<F> {
+    let powers: Vec<F> = successors(Some(F::one()), |p| Some(*p * r))
+        .take(folding_factor)
+        .collect();
+
+    let mut coeffs = vec![F::zero(); poly.coeffs.len().div_ceil(folding_factor)];
+    for (i, c) in poly.coeffs.iter().enumerate() {
+        coeffs[i / folding_factor] += powers[i % folding_factor] * c;
+    }
+    DensePolynomial::from_coefficients_vec(coeffs)
 }
 
 #[cfg(test)]
@@ -129,4 +272,19 @@ mod tests {
         let expected = DensePolynomial::from_coefficients_vec(vec![Fq::from(3), Fq::from(7)]);
         assert_eq!(folded, expected);
     }
+
+    #[test]
+    fn test_fold_polynomial_by_four() {
+        // f(x) = 1 + 2x + ... + 8x⁷ = f_0(x⁴) + x·f_1(x⁴) + x²·f_2(x⁴) + x³·f_3(x⁴)
+        // fold with r=2: f_0 + 2f_1 + 4f_2 + 8f_3 = (1+4+12+32) + (5+12+28+64)x
+        let poly =
+            DensePolynomial::from_coefficients_vec((1..=8u64).map(Fq::from).collect::<Vec<_>>());
+        let folded = fold_polynomial_by(&poly, Fq::from(2), 4);
+        let expected = DensePolynomial::from_coefficients_vec(vec![Fq::from(49), Fq::from(109)]);
+        assert_eq!(folded, expected);
+        assert_eq!(
+            fold_polynomial_by(&poly, Fq::from(3), 2),
+            fold_polynomial(&poly, Fq::from(3))
+        );
+    }
 }
@@ -1,16 +1,24 @@
 use ark_ff::PrimeField;
-use ark_poly::{EvaluationDomain, GeneralEvaluationDomain};
+use ark_poly::{
+    DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain, univariate::DensePolynomial,
+};
+use ark_std::{iter::successors, vec, vec::Vec};
 
 use crate::{
-    crypto::{merkle::verify_merkle_proof, transcript::Transcript},
-    fri::prover::{Decommitment, FriProof},
-    polynomial::domain,
+    crypto::{merkle::has_height, transcript::Transcript},
+    fri::{
+        check_degree_bound,
+        prover::{Decommitment, FriProof},
+    },
+    polynomial::{domain, interpolation::Interpolator},
 };
 
 pub fn verify<F: PrimeField>(
     proof: &FriProof<F>,
     transcript: &mut Transcript<F>,
 ) -> anyhow::Result<()> {
+    check_shape(proof)?;
+
     let random_r_list: Vec<F> = proof
         .layers_root
         .iter()
@@ -27,69 +35,142 @@ pub fn verify<F: PrimeField>(
         .into_iter()
         .zip(proof.decommitment_list.iter())
     {
-        verify_single_query(
-            query_idx,
-            decommitment,
-            &random_r_list,
-            proof.domain_size,
-            proof.coset,
-            proof.const_val,
-        )?;
+        verify_single_query(proof, query_idx, decommitment, &random_r_list)?;
+    }
+
+    Ok(())
+}
+
+// Also holds the proof to the rate it claims: the first layer may have degree
+// below domain_size / blowup_factor, each layer divides that bound by the folding
+// factor, and the constant the proof ends on must fit what is left. A proof with
+// more layers than the rate allows certifies a higher degree than it should.
+pub fn verify_with_blowup<F: PrimeField>(
+    proof: &FriProof<F>,
+    blowup_factor: usize,
+    transcript: &mut Transcript<F>,
+) -> anyhow::Result<()> {
+    verify(proof, transcript)?;
+
+    if blowup_factor == 0 {
+        return Err(anyhow::anyhow!("blowup factor must be nonzero"));
+    }
+    let num_coeffs = proof
+        .layers_root
+        .iter()
+        .fold(proof.domain_size / blowup_factor, |n, _| {
+            n / proof.folding_factor
+        });
+    let last_layer = DensePolynomial::from_coefficients_vec(vec![proof.const_val]);
+    let fits = num_coeffs
+        .checked_sub(1)
+        .is_some_and(|degree_bound| check_degree_bound(&last_layer, degree_bound));
+    if !fits {
+        return Err(anyhow::anyhow!("too many layers for the blowup factor"));
+    }
+
+    Ok(())
+}
+
+// Rejects proofs whose sizes don't line up, so malformed input fails
+// verification instead of panicking on an index or an unsupported domain.
+fn check_shape<F: PrimeField>(proof: &FriProof<F>) -> anyhow::Result<()> {
+    let num_layers = proof.layers_root.len();
+    let k = proof.folding_factor;
+
+    if k < 2 || !k.is_power_of_two() {
+        return Err(anyhow::anyhow!("folding factor must be a power of two"));
+    }
+    let folds_fit = num_layers
+        .checked_mul(k.ilog2() as usize)
+        .and_then(|bits| u32::try_from(bits).ok())
+        .and_then(|bits| proof.domain_size.checked_shr(bits))
+        .is_some_and(|last| last > 0);
+    if !proof.domain_size.is_power_of_two() || !folds_fit {
+        return Err(anyhow::anyhow!("domain too small for the number of layers"));
+    }
+    if GeneralEvaluationDomain::<F>::new(proof.domain_size).is_none() {
+        return Err(anyhow::anyhow!("unsupported domain size"));
+    }
+    if proof.coset.is_zero() {
+        return Err(anyhow::anyhow!("coset shift must be nonzero"));
+    }
+    if proof.decommitment_list.len() != proof.number_of_queries {
+        return Err(anyhow::anyhow!("wrong number of decommitments"));
+    }
+
+    let well_formed = proof.decommitment_list.iter().all(|d| {
+        d.evaluations.len() == num_layers
+            && d.auth_paths.len() == num_layers
+            && d.evaluations.iter().all(|evals| evals.len() == k)
+            && d.auth_paths.iter().all(|paths| paths.len() == k)
+    });
+    if !well_formed {
+        return Err(anyhow::anyhow!(
+            "decommitment does not match the number of layers"
+        ));
     }
 
     Ok(())
 }
 
 fn verify_single_query<F: PrimeField>(
+    proof: &FriProof<F>,
     query_idx: usize,
     decommitment: &Decommitment<F>,
     random_r_list: &[F],
-    domain_size: usize,
-    coset: F,
-    const_val: F,
 ) -> anyhow::Result<()> {
+    let folding_factor = proof.folding_factor;
     let mut curr_idx = query_idx;
-    let mut curr_coset = coset;
-    let mut curr_domain_size = domain_size;
+    let mut curr_coset = proof.coset;
+    let mut curr_domain_size = proof.domain_size;
 
     let num_layers = random_r_list.len();
-    let two = F::from(2u64);
 
     for (layer_i, &r) in random_r_list.iter().enumerate() {
-        let sym_idx = (curr_idx + curr_domain_size / 2) % curr_domain_size;
+        let stride = curr_domain_size / folding_factor;
+        curr_idx %= stride;
 
-        if !verify_merkle_proof(&decommitment.auth_paths[layer_i]) {
-            return Err(anyhow::anyhow!("merkle proof invalid"));
-        }
-
-        if !verify_merkle_proof(&decommitment.sym_auth_paths[layer_i]) {
-            return Err(anyhow::anyhow!("merkle proof invalid"));
-        }
-
-        if decommitment.auth_paths[layer_i].index != curr_idx
-            || decommitment.sym_auth_paths[layer_i].index != sym_idx
+        let height = curr_domain_size.ilog2() as usize;
+        let evaluations = &decommitment.evaluations[layer_i];
+        for (j, (path, value)) in decommitment.auth_paths[layer_i]
+            .iter()
+            .zip(evaluations)
+            .enumerate()
         {
-            return Err(anyhow::anyhow!("wrong index"));
+            if !has_height(path, height) || !path.verify(proof.layers_root[layer_i]) {
+                return Err(anyhow::anyhow!("merkle proof invalid"));
+            }
+            if path.index != curr_idx + j * stride {
+                return Err(anyhow::anyhow!("wrong index"));
+            }
+            if path.leaf_val != *value {
+                return Err(anyhow::anyhow!("evaluation does not match its merkle leaf"));
+            }
         }
 
-        let f_x = decommitment.evaluations[layer_i];
-        let f_neg_x = decommitment.sym_evaluations[layer_i];
-
+        // The k openings sit on x·ζ^j; the unique degree < k polynomial through
+        // them evaluated at r is the folded value at x^k.
         let domain: GeneralEvaluationDomain<F> = domain(curr_domain_size);
-        let w = domain.element(curr_idx) * curr_coset;
-        let folded = (f_x + f_neg_x) / two + r * (f_x - f_neg_x) / (two * w);
+        let x = domain.element(curr_idx) * curr_coset;
+        let zeta = domain.element(stride);
+        let points: Vec<F> = successors(Some(x), |p| Some(*p * zeta))
+            .take(folding_factor)
+            .collect();
+        let folded = Interpolator::new(&points).evaluate(evaluations, r);
 
-        if layer_i == num_layers - 1 {
-            if folded != const_val {
-                return Err(anyhow::anyhow!("fodling wrong"));
-            }
-        } else if folded != decommitment.evaluations[layer_i + 1] {
+        let expected = if layer_i == num_layers - 1 {
+            proof.const_val
+        } else {
+            let next_stride = stride / folding_factor;
+            decommitment.evaluations[layer_i + 1][curr_idx / next_stride]
+        };
+        if folded != expected {
             return Err(anyhow::anyhow!("folding wrong"));
         }
 
-        curr_domain_size /= 2;
-        curr_idx %= curr_domain_size;
-        curr_coset = curr_coset.square();
+        curr_domain_size = stride;
+        curr_coset = curr_coset.pow([folding_factor as u64]);
     }
 
     Ok(())
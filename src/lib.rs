@@ -1,5 +1,15 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
 pub mod crypto;
+pub mod error;
 pub mod field;
 pub mod fri;
 pub mod polynomial;
+mod serialize;
 pub mod stark;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,606 @@
+use ark_ff::{BitIteratorBE, PrimeField, Zero};
+use ark_poly::{
+    DenseUVPolynomial, Polynomial,
+    univariate::{DenseOrSparsePolynomial, DensePolynomial},
+};
+use ark_std::{vec, vec::Vec};
+
+use crate::{
+    error::{FieldError, PolyError},
+    field::arithmetic::checked_inv,
+};
+
+pub fn div_rem<F: PrimeField>(
+    numerator: &DensePolynomial<F>,
+    denominator: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    DenseOrSparsePolynomial::from(numerator)
+        .divide_with_q_and_r(&DenseOrSparsePolynomial::from(denominator))
+        .expect("division failed")
+}
+
+// a / b when b divides a exactly.
+pub fn exact_div<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> Result<DensePolynomial<F>, PolyError> {
+    if b.is_zero() {
+        return Err(PolyError::ZeroDivisor);
+    }
+    let (q, r) = div_rem(a, b);
+    match r.is_zero() {
+        true => Ok(q),
+        false => Err(PolyError::InexactDivision),
+    }
+}
+
+// Monic gcd; gcd(0, 0) is the zero polynomial.
+pub fn poly_gcd<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> DensePolynomial<F> {
+    let mut a = a.clone();
+    let mut b = b.clone();
+
+    while !b.is_zero() {
+        let (_, r) = div_rem(&a, &b);
+        a = b;
+        b = r;
+    }
+
+    make_monic(&a)
+}
+
+// (g, s, t) with s·a + t·b = g = poly_gcd(a, b).
+pub fn poly_extended_gcd<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>) {
+    let one = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (one.clone(), DensePolynomial::zero());
+    let (mut t0, mut t1) = (DensePolynomial::zero(), one);
+
+    while !r1.is_zero() {
+        let (q, r) = div_rem(&r0, &r1);
+        let s = &s0 - &(&q * &s1);
+        let t = &t0 - &(&q * &t1);
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s);
+        (t0, t1) = (t1, t);
+    }
+
+    match r0.last() {
+        Some(lc) => {
+            let lc_inv = lc.inverse().expect("leading coefficient is nonzero");
+            (&r0 * lc_inv, &s0 * lc_inv, &t0 * lc_inv)
+        }
+        None => (r0, s0, t0),
+    }
+}
+
+// The unique polynomial of degree below deg(m_1·...·m_k) that is congruent to
+// r_i modulo m_i for every (m_i, r_i). The moduli must be pairwise coprime.
+pub fn poly_crt<F: PrimeField>(
+    residues: &[(DensePolynomial<F>, DensePolynomial<F>)],
+) -> DensePolynomial<F> {
+    let mut result = DensePolynomial::zero();
+    let mut modulus = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+
+    // Garner: lift x ≡ result (mod M) to also satisfy x ≡ r (mod m) as
+    // x = result + M·((r - result)·M^-1 mod m)
+    for (m, r) in residues {
+        let (g, m_inv, _) = poly_extended_gcd(&div_rem(&modulus, m).1, m);
+        assert!(
+            g.degree() == 0 && !g.is_zero(),
+            "moduli must be pairwise coprime"
+        );
+
+        let correction = div_rem(&(&(r - &result) * &m_inv), m).1;
+        result = &result + &(&modulus * &correction);
+        modulus = &modulus * m;
+    }
+
+    result
+}
+
+pub fn is_monic<F: PrimeField>(poly: &DensePolynomial<F>) -> bool {
+    poly.last().is_some_and(|lc| lc.is_one())
+}
+
+// The zero polynomial is returned unchanged.
+pub fn make_monic<F: PrimeField>(poly: &DensePolynomial<F>) -> DensePolynomial<F> {
+    match poly.last() {
+        Some(lc) => poly * lc.inverse().expect("leading coefficient is nonzero"),
+        None => poly.clone(),
+    }
+}
+
+// (lc, monic) with lc · monic == poly; the zero polynomial normalizes to (0, 0).
+pub fn normalize<F: PrimeField>(poly: &DensePolynomial<F>) -> (F, DensePolynomial<F>) {
+    let lc = poly.last().copied().unwrap_or_else(F::zero);
+    (lc, make_monic(poly))
+}
+
+// x^deg · P(1/x). Low-order zero coefficients become leading zeros and are
+// trimmed, so reversing twice is the identity only when P(0) != 0.
+pub fn reverse_poly<F: PrimeField>(poly: &DensePolynomial<F>) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec(poly.coeffs.iter().rev().copied().collect())
+}
+
+// f^-1 mod x^n by Newton iteration, doubling the precision each step:
+// g <- g·(2 - f·g) mod x^2k. Requires f(0) != 0.
+pub fn inverse_mod_xn<F: PrimeField>(f: &DensePolynomial<F>, n: usize) -> DensePolynomial<F> {
+    let f0 = f.coeffs.first().copied().unwrap_or_else(F::zero);
+    let f0_inv = f0.inverse().expect("constant term must be invertible");
+    let two = DensePolynomial::from_coefficients_vec(vec![F::from(2u64)]);
+
+    let mut g = DensePolynomial::from_coefficients_vec(vec![f0_inv]);
+    let mut precision = 1;
+    while precision < n {
+        precision = (2 * precision).min(n);
+        let fg = truncate(&(&truncate(f, precision) * &g), precision);
+        g = truncate(&(&g * &(&two - &fg)), precision);
+    }
+
+    truncate(&g, n)
+}
+
+// Quotient and remainder in O(n log n): the reversed quotient is rev(a) / rev(b)
+// mod x^(deg a - deg b + 1), and the remainder follows from a - q·b.
+pub fn fast_divide<F: PrimeField>(
+    numerator: &DensePolynomial<F>,
+    denominator: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    assert!(!denominator.is_zero(), "division by the zero polynomial");
+    if numerator.is_zero() || numerator.degree() < denominator.degree() {
+        return (DensePolynomial::zero(), numerator.clone());
+    }
+
+    let m = numerator.degree() - denominator.degree();
+    let inv = inverse_mod_xn(&reverse_poly(denominator), m + 1);
+    let q_rev = truncate(&(&reverse_poly(numerator) * &inv), m + 1);
+
+    // pad to exactly m + 1 coefficients so the reversal lines up
+    let mut q_coeffs = q_rev.coeffs;
+    q_coeffs.resize(m + 1, F::zero());
+    q_coeffs.reverse();
+    let quotient = DensePolynomial::from_coefficients_vec(q_coeffs);
+    let remainder = numerator - &(&quotient * denominator);
+
+    (quotient, remainder)
+}
+
+fn truncate<F: PrimeField>(poly: &DensePolynomial<F>, n: usize) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_slice(&poly.coeffs[..n.min(poly.coeffs.len())])
+}
+
+// Power sums p_1..p_k of the roots (with multiplicity, over the splitting field)
+// via Newton's identities. With b_i the coefficient of x^(n-i) in the monic
+// polynomial: p_j = -(b_1·p_(j-1) + ... + b_(j-1)·p_1 + j·b_j), b_j = 0 for j > n.
+pub fn power_sums<F: PrimeField>(poly: &DensePolynomial<F>, k: usize) -> Vec<F> {
+    let (_, monic) = normalize(poly);
+    let n = monic.degree();
+    let b = |i: usize| match i {
+        i if i <= n && !monic.is_zero() => monic.coeffs[n - i],
+        _ => F::zero(),
+    };
+
+    let mut sums: Vec<F> = Vec::with_capacity(k);
+    for j in 1..=k {
+        let mut acc = F::from(j as u64) * b(j);
+        for i in 1..j {
+            acc += b(i) * sums[j - i - 1];
+        }
+        sums.push(-acc);
+    }
+    sums
+}
+
+// The monic polynomial of degree sums.len() whose roots have the given power
+// sums. Inverting Newton's identities divides by j, so this fails once some
+// j ≡ 0 mod p.
+pub fn poly_from_power_sums<F: PrimeField>(sums: &[F]) -> Result<DensePolynomial<F>, FieldError> {
+    let n = sums.len();
+    let mut b = vec![F::one()];
+    for j in 1..=n {
+        let mut acc = sums[j - 1];
+        for i in 1..j {
+            acc += b[i] * sums[j - i - 1];
+        }
+        b.push(-acc * checked_inv(F::from(j as u64))?);
+    }
+
+    b.reverse();
+    Ok(DensePolynomial::from_coefficients_vec(b))
+}
+
+pub fn derivative<F: PrimeField>(poly: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let coeffs = poly
+        .coeffs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| F::from(i as u64) * c)
+        .collect();
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+// Product of the distinct irreducible factors of `poly`, in monic form.
+pub fn squarefree_part<F: PrimeField>(poly: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if poly.degree() == 0 {
+        return make_monic(poly);
+    }
+
+    let d = derivative(poly);
+    if d.is_zero() {
+        // f' = 0 only when f(x) = g(x^p) = g(x)^p
+        return squarefree_part(&pth_root(poly));
+    }
+
+    let mut g = poly_gcd(poly, &d);
+    let mut result = div_rem(poly, &g).0;
+
+    // Factors whose multiplicity is a multiple of p divide f' and survive
+    // only in g; strip everything else from g and recurse on that p-th power.
+    loop {
+        let common = poly_gcd(&g, &result);
+        if common.degree() == 0 {
+            break;
+        }
+        g = div_rem(&g, &common).0;
+    }
+    if g.degree() > 0 {
+        result = &result * &squarefree_part(&g);
+    }
+
+    make_monic(&result)
+}
+
+fn pth_root<F: PrimeField>(poly: &DensePolynomial<F>) -> DensePolynomial<F> {
+    // Only reached when deg f >= p, so the characteristic fits in one limb
+    let p = F::MODULUS.as_ref()[0] as usize;
+    DensePolynomial::from_coefficients_vec(poly.coeffs.iter().step_by(p).copied().collect())
+}
+
+pub fn pow_mod<F: PrimeField, S: AsRef<[u64]>>(
+    base: &DensePolynomial<F>,
+    exp: S,
+    modulus: &DensePolynomial<F>,
+) -> DensePolynomial<F> {
+    let (_, base) = div_rem(base, modulus);
+    let mut result = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+
+    for bit in BitIteratorBE::without_leading_zeros(exp) {
+        result = div_rem(&(&result * &result), modulus).1;
+        if bit {
+            result = div_rem(&(&result * &base), modulus).1;
+        }
+    }
+
+    div_rem(&result, modulus).1
+}
+
+// Distinct roots in ascending order. Constant polynomials (including zero) yield no roots.
+pub fn roots<F: PrimeField>(poly: &DensePolynomial<F>) -> Vec<F> {
+    if poly.degree() == 0 {
+        return Vec::new();
+    }
+
+    // gcd(f, x^p - x) keeps exactly the linear factors of f, each once
+    let x = DensePolynomial::from_coefficients_vec(vec![F::zero(), F::one()]);
+    let x_p = pow_mod(&x, F::MODULUS, poly);
+    let split = poly_gcd(poly, &(&x_p - &x));
+
+    let mut roots = Vec::with_capacity(split.degree());
+    split_linear_factors(&split, &mut roots);
+    roots.sort();
+    roots
+}
+
+fn split_linear_factors<F: PrimeField>(poly: &DensePolynomial<F>, roots: &mut Vec<F>) {
+    match poly.degree() {
+        0 => return,
+        1 => {
+            roots.push(-poly.coeffs[0] / poly.coeffs[1]);
+            return;
+        }
+        _ => {}
+    }
+
+    // (x + a)^((p-1)/2) - 1 vanishes on roughly half of the roots, so some
+    // small shift a separates them
+    let one = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+    let mut a = F::zero();
+    loop {
+        let shifted = DensePolynomial::from_coefficients_vec(vec![a, F::one()]);
+        let h = &pow_mod(&shifted, F::MODULUS_MINUS_ONE_DIV_TWO, poly) - &one;
+        let factor = poly_gcd(poly, &h);
+
+        if factor.degree() > 0 && factor.degree() < poly.degree() {
+            let (cofactor, _) = div_rem(poly, &factor);
+            split_linear_factors(&factor, roots);
+            split_linear_factors(&cofactor, roots);
+            return;
+        }
+
+        a += F::one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::Fq;
+
+    fn linear(root: u64) -> DensePolynomial<Fq> {
+        DensePolynomial::from_coefficients_vec(vec![-Fq::from(root), Fq::from(1)])
+    }
+
+    #[test]
+    fn test_roots_known() {
+        let poly = &(&linear(1) * &linear(2)) * &linear(5);
+        let found = roots(&poly);
+
+        assert_eq!(found, vec![Fq::from(1), Fq::from(2), Fq::from(5)]);
+        for root in found {
+            assert!(poly.evaluate(&root).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_roots_none() {
+        // 7 generates the multiplicative group, so it is not a square
+        let poly =
+            DensePolynomial::from_coefficients_vec(vec![-Fq::from(7), Fq::from(0), Fq::from(1)]);
+        assert!(roots(&poly).is_empty());
+    }
+
+    #[test]
+    fn test_exact_div() {
+        let a = &linear(1) * &linear(2);
+        assert_eq!(exact_div(&a, &linear(2)), Ok(linear(1)));
+        assert_eq!(exact_div(&a, &linear(3)), Err(PolyError::InexactDivision));
+        assert_eq!(
+            exact_div(&a, &DensePolynomial::zero()),
+            Err(PolyError::ZeroDivisor)
+        );
+    }
+
+    #[test]
+    fn test_division_edge_cases() {
+        let poly = |coeffs: &[u64]| {
+            DensePolynomial::from_coefficients_vec(coeffs.iter().map(|c| Fq::from(*c)).collect())
+        };
+        let constant = |c: u64| poly(&[c]);
+
+        // equal degrees: constant quotient, lower-degree remainder
+        let a = poly(&[5, 3, 4]);
+        let b = poly(&[1, 1, 2]);
+        let expected_q = constant(2);
+        let expected_r = &a - &(&expected_q * &b);
+        assert_eq!(expected_r.degree(), 1);
+        assert_eq!(div_rem(&a, &b), (expected_q.clone(), expected_r.clone()));
+        assert_eq!(fast_divide(&a, &b), (expected_q, expected_r));
+
+        // exact division: the remainder is the zero polynomial, with no trailing zeros
+        let exact = &(&linear(1) * &linear(2)) * &linear(3);
+        for divisor in [linear(2), &linear(1) * &linear(3), exact.clone()] {
+            for (q, r) in [div_rem(&exact, &divisor), fast_divide(&exact, &divisor)] {
+                assert!(r.is_zero() && r.coeffs.is_empty());
+                assert_eq!(q.coeffs.last().map(Zero::is_zero), Some(false));
+                assert_eq!(&q * &divisor, exact);
+            }
+        }
+
+        // numerator of lower degree: zero quotient, numerator as remainder
+        let low = poly(&[7, 1]);
+        for (q, r) in [div_rem(&low, &b), fast_divide(&low, &b)] {
+            assert!(q.is_zero());
+            assert_eq!(r, low);
+        }
+    }
+
+    #[test]
+    fn test_make_monic() {
+        let poly =
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(2), Fq::from(4), Fq::from(8)]);
+        assert!(!is_monic(&poly));
+
+        let monic = make_monic(&poly);
+        assert!(is_monic(&monic));
+        assert_eq!(&monic * Fq::from(8), poly);
+    }
+
+    #[test]
+    fn test_make_monic_zero() {
+        let zero = DensePolynomial::<Fq>::zero();
+        assert!(!is_monic(&zero));
+        assert!(make_monic(&zero).is_zero());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let poly =
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(6), -Fq::from(3), Fq::from(9)]);
+        let (lc, monic) = normalize(&poly);
+
+        assert_eq!(lc, Fq::from(9));
+        assert!(is_monic(&monic));
+        assert_eq!(&monic * lc, poly);
+
+        let (lc, monic) = normalize(&DensePolynomial::<Fq>::zero());
+        assert!(lc.is_zero() && monic.is_zero());
+    }
+
+    #[test]
+    fn test_reverse_poly() {
+        let poly =
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2), Fq::from(3)]);
+        let reversed = reverse_poly(&poly);
+        assert_eq!(reversed.coeffs, vec![Fq::from(3), Fq::from(2), Fq::from(1)]);
+        assert_eq!(reverse_poly(&reversed), poly);
+
+        // x^2 + x reverses to 1 + x, losing the factor of x
+        let no_constant =
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(0), Fq::from(1), Fq::from(1)]);
+        assert_eq!(reverse_poly(&no_constant).degree(), 1);
+        assert!(reverse_poly(&DensePolynomial::<Fq>::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_inverse_mod_xn() {
+        let f = DensePolynomial::from_coefficients_vec(vec![Fq::from(3), Fq::from(1), Fq::from(4)]);
+        let g = inverse_mod_xn(&f, 10);
+        let product = truncate(&(&f * &g), 10);
+        assert_eq!(
+            product,
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(1)])
+        );
+    }
+
+    #[test]
+    fn test_fast_divide_matches_div_rem() {
+        let rng = &mut ark_std::test_rng();
+        for (deg_a, deg_b) in [(1000, 300), (513, 512), (700, 1), (5, 9)] {
+            let a = DensePolynomial::<Fq>::rand(deg_a, rng);
+            let b = DensePolynomial::<Fq>::rand(deg_b, rng);
+            assert_eq!(fast_divide(&a, &b), div_rem(&a, &b));
+        }
+
+        // exact division with zero low-order coefficients on both sides
+        let x = DensePolynomial::from_coefficients_vec(vec![Fq::from(0), Fq::from(1)]);
+        let b = &x * &linear(2);
+        let a = &b * &(&x * &linear(7));
+        let (q, r) = fast_divide(&a, &b);
+        assert_eq!(q, &x * &linear(7));
+        assert!(r.is_zero());
+    }
+
+    #[test]
+    fn test_power_sums() {
+        let poly = &(&linear(1) * &linear(2)) * &(&linear(5) * Fq::from(3));
+        let expected: Vec<Fq> = (1..=6u32)
+            .map(|j| Fq::from(1 + 2u64.pow(j) + 5u64.pow(j)))
+            .collect();
+
+        assert_eq!(power_sums(&poly, 6), expected);
+        assert_eq!(
+            poly_from_power_sums(&expected[..3]).unwrap(),
+            make_monic(&poly)
+        );
+    }
+
+    #[test]
+    fn test_power_sums_repeated_roots() {
+        // (x - 3)^2: p_j = 2·3^j
+        let poly = &linear(3) * &linear(3);
+        let expected: Vec<Fq> = (1..=4u32).map(|j| Fq::from(2 * 3u64.pow(j))).collect();
+        assert_eq!(power_sums(&poly, 4), expected);
+        assert!(
+            power_sums(&DensePolynomial::<Fq>::zero(), 3)
+                .iter()
+                .all(Zero::is_zero)
+        );
+    }
+
+    #[test]
+    fn test_poly_extended_gcd() {
+        let a = &(&linear(1) * &linear(2)) * &linear(4);
+        let b = &(&linear(2) * &linear(3)) * Fq::from(5);
+        let (g, s, t) = poly_extended_gcd(&a, &b);
+
+        assert_eq!(g, linear(2));
+        assert_eq!(&(&s * &a) + &(&t * &b), g);
+    }
+
+    #[test]
+    fn test_poly_crt() {
+        let poly = DensePolynomial::from_coefficients_vec(vec![
+            Fq::from(7),
+            -Fq::from(3),
+            Fq::from(0),
+            Fq::from(11),
+        ]);
+
+        // remainders modulo x - a are the evaluations at a
+        let residues: Vec<_> = [1u64, 2, 3, 9]
+            .into_iter()
+            .map(|a| {
+                let value = poly.evaluate(&Fq::from(a));
+                (
+                    linear(a),
+                    DensePolynomial::from_coefficients_vec(vec![value]),
+                )
+            })
+            .collect();
+        assert_eq!(poly_crt(&residues), poly);
+
+        // a quadratic modulus alongside a linear one
+        let quadratic = &linear(5) * &linear(6);
+        let residues = vec![
+            (quadratic.clone(), div_rem(&poly, &quadratic).1),
+            (linear(7), div_rem(&poly, &linear(7)).1),
+        ];
+        let reconstructed = poly_crt(&residues);
+        assert!(reconstructed.degree() < 3);
+        assert_eq!(div_rem(&reconstructed, &quadratic).1, residues[0].1);
+        assert_eq!(div_rem(&reconstructed, &linear(7)).1, residues[1].1);
+    }
+
+    #[test]
+    #[should_panic(expected = "moduli must be pairwise coprime")]
+    fn test_poly_crt_not_coprime() {
+        let one = DensePolynomial::from_coefficients_vec(vec![Fq::from(1)]);
+        poly_crt(&[(linear(2), one.clone()), (&linear(2) * &linear(3), one)]);
+    }
+
+    #[test]
+    fn test_derivative() {
+        // d/dx (1 + 2x + 3x^2) = 2 + 6x
+        let poly =
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2), Fq::from(3)]);
+        let expected = DensePolynomial::from_coefficients_vec(vec![Fq::from(2), Fq::from(6)]);
+        assert_eq!(derivative(&poly), expected);
+    }
+
+    #[test]
+    fn test_squarefree_part() {
+        let poly = &(&linear(1) * &linear(1)) * &(&linear(2) * Fq::from(3));
+        assert_eq!(squarefree_part(&poly), &linear(1) * &linear(2));
+    }
+
+    #[test]
+    fn test_squarefree_part_already_squarefree() {
+        let poly = &linear(1) * &linear(2);
+        assert_eq!(squarefree_part(&poly), poly);
+    }
+
+    #[test]
+    fn test_poly_gcd() {
+        let a = &linear(1) * &linear(2);
+        let b = &linear(2) * &linear(3);
+        assert_eq!(poly_gcd(&a, &b), linear(2));
+    }
+
+    #[test]
+    fn test_algebra_over_a_second_field() {
+        use crate::field::test_fields::BabyBear;
+
+        // the helpers only rely on PrimeField, so both fields go through the same code
+        fn check<F: PrimeField>() {
+            let linear =
+                |root: u64| DensePolynomial::from_coefficients_vec(vec![-F::from(root), F::one()]);
+            let a = &(&linear(1) * &linear(2)) * &linear(5);
+            let b = &linear(2) * &linear(9);
+
+            assert_eq!(poly_gcd(&a, &b), linear(2));
+            assert_eq!(exact_div(&a, &linear(5)).unwrap(), &linear(1) * &linear(2));
+            assert_eq!(fast_divide(&a, &b), div_rem(&a, &b));
+            assert_eq!(roots(&a), [1u64, 2, 5].map(F::from).to_vec());
+        }
+
+        check::<Fq>();
+        check::<BabyBear>();
+    }
+}
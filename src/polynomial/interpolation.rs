@@ -0,0 +1,185 @@
+use ark_ff::{PrimeField, batch_inversion};
+use ark_poly::{
+    DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain, univariate::DensePolynomial,
+};
+use ark_std::{vec, vec::Vec};
+
+use crate::polynomial::{divide_by_linear, shift_poly};
+
+// L_0, ..., L_{n-1} over `domain`. Since L_i(x) = L_0(ω^{-i}·x) and every
+// coefficient of L_0 is 1/n, each basis polynomial is a rescaled copy of L_0.
+pub fn lagrange_basis<F: PrimeField>(
+    domain: &GeneralEvaluationDomain<F>,
+) -> Vec<DensePolynomial<F>> {
+    let n = domain.size();
+    let l0 = DensePolynomial::from_coefficients_vec(vec![domain.size_inv(); n]);
+
+    let mut basis = Vec::with_capacity(n);
+    let mut omega_inv_i = F::one();
+    for _ in 0..n {
+        basis.push(shift_poly(&l0, omega_inv_i));
+        omega_inv_i *= domain.group_gen_inv();
+    }
+    basis
+}
+
+pub fn interpolate_with_basis<F: PrimeField>(
+    basis: &[DensePolynomial<F>],
+    values: &[F],
+) -> DensePolynomial<F> {
+    assert_eq!(basis.len(), values.len(), "one value per basis polynomial");
+
+    let mut result = DensePolynomial::from_coefficients_vec(vec![]);
+    for (l_i, value) in basis.iter().zip(values) {
+        result += (*value, l_i);
+    }
+    result
+}
+
+// Interpolation over a fixed set of distinct points. The barycentric weights
+// w_i = 1 / prod_{j != i} (x_i - x_j) and M(x) = prod (x - x_j) are computed once,
+// so each new value vector only costs O(n^2) to interpolate and O(n) to evaluate.
+#[derive(Clone, Debug)]
+pub struct Interpolator<F: PrimeField> {
+    points: Vec<F>,
+    weights: Vec<F>,
+    vanishing: DensePolynomial<F>,
+}
+
+impl<F: PrimeField> Interpolator<F> {
+    pub fn new(points: &[F]) -> Self {
+        let mut weights: Vec<F> = points
+            .iter()
+            .enumerate()
+            .map(|(i, x_i)| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(_, x_j)| *x_i - x_j)
+                    .product()
+            })
+            .collect();
+        assert!(
+            weights.iter().all(|w| !w.is_zero()),
+            "interpolation points must be distinct"
+        );
+        batch_inversion(&mut weights);
+
+        let mut vanishing = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+        for x in points {
+            vanishing = &vanishing * &DensePolynomial::from_coefficients_vec(vec![-*x, F::one()]);
+        }
+
+        Self {
+            points: points.to_vec(),
+            weights,
+            vanishing,
+        }
+    }
+
+    pub fn interpolate(&self, values: &[F]) -> DensePolynomial<F> {
+        assert_eq!(self.points.len(), values.len(), "one value per point");
+
+        let mut result = DensePolynomial::from_coefficients_vec(vec![]);
+        for ((x, w), y) in self.points.iter().zip(&self.weights).zip(values) {
+            let (l_i, _) = divide_by_linear(&self.vanishing, *x);
+            result += (*w * y, &l_i);
+        }
+        result
+    }
+
+    // Evaluates the interpolant at z without building it.
+    pub fn evaluate(&self, values: &[F], z: F) -> F {
+        assert_eq!(self.points.len(), values.len(), "one value per point");
+
+        if let Some(i) = self.points.iter().position(|x| *x == z) {
+            return values[i];
+        }
+
+        let mut denominators: Vec<F> = self.points.iter().map(|x| z - x).collect();
+        batch_inversion(&mut denominators);
+
+        let sum: F = denominators
+            .iter()
+            .zip(&self.weights)
+            .zip(values)
+            .map(|((d, w), y)| *d * w * y)
+            .sum();
+        sum * self.points.iter().map(|x| z - x).product::<F>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_poly::Polynomial;
+
+    use super::*;
+    use crate::field::Fq;
+    use crate::polynomial::{domain, fast_interpolate};
+
+    #[test]
+    fn test_lagrange_basis_is_unit_vectors() {
+        let domain = domain::<Fq>(8);
+        let basis = lagrange_basis(&domain);
+
+        for (i, l_i) in basis.iter().enumerate() {
+            let evals = domain.fft(&l_i.coeffs);
+            for (j, eval) in evals.iter().enumerate() {
+                assert_eq!(*eval, if i == j { Fq::from(1) } else { Fq::from(0) });
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_with_basis() {
+        let domain = domain::<Fq>(16);
+        let basis = lagrange_basis(&domain);
+
+        for seed in 0..4u64 {
+            let values: Vec<Fq> = (0..16).map(|i| Fq::from(seed * 31 + i * i)).collect();
+            assert_eq!(
+                interpolate_with_basis(&basis, &values),
+                fast_interpolate(values, &domain)
+            );
+        }
+    }
+
+    #[test]
+    fn test_interpolator_matches_fft_interpolation() {
+        let domain = domain::<Fq>(16);
+        let interpolator = Interpolator::new(&domain.elements().collect::<Vec<_>>());
+
+        for seed in 0..4u64 {
+            let values: Vec<Fq> = (0..16).map(|i| Fq::from(seed * 17 + i * i * i)).collect();
+            let poly = fast_interpolate(values.clone(), &domain);
+
+            assert_eq!(interpolator.interpolate(&values), poly);
+            let z = Fq::from(1000 + seed);
+            assert_eq!(interpolator.evaluate(&values, z), poly.evaluate(&z));
+        }
+    }
+
+    #[test]
+    fn test_interpolator_arbitrary_points() {
+        let points = [1u64, 4, 9, 20].map(Fq::from);
+        let values = [3u64, 1, 4, 1].map(Fq::from);
+        let interpolator = Interpolator::new(&points);
+        let poly = interpolator.interpolate(&values);
+
+        for (x, y) in points.iter().zip(&values) {
+            assert_eq!(poly.evaluate(x), *y);
+            assert_eq!(interpolator.evaluate(&values, *x), *y);
+        }
+        assert_eq!(
+            interpolator.evaluate(&values, Fq::from(7)),
+            poly.evaluate(&Fq::from(7))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation points must be distinct")]
+    fn test_interpolator_repeated_point() {
+        Interpolator::new(&[Fq::from(1), Fq::from(2), Fq::from(1)]);
+    }
+}
@@ -1,21 +1,80 @@
-use ark_ff::{FftField, PrimeField};
+pub mod algebra;
+pub mod interpolation;
+pub mod multivariate;
+pub mod ntt;
+
+use ark_ff::{FftField, PrimeField, Zero, batch_inversion};
 use ark_poly::{
     DenseUVPolynomial, EvaluationDomain, Evaluations, GeneralEvaluationDomain, Polynomial,
     univariate::{DensePolynomial, SparsePolynomial},
 };
+use ark_std::{iter, rand::Rng, vec, vec::Vec};
+
+use self::ntt::NttDomain;
 
 pub fn poly_pow<F: PrimeField>(poly: &DensePolynomial<F>, exp: usize) -> DensePolynomial<F> {
-    match exp {
-        0 => DensePolynomial::from_coefficients_vec(vec![F::one()]),
-        1 => poly.clone(),
-        _ => {
-            let mut result = poly.clone();
-            for _ in 1..exp {
-                result = &result * poly;
-            }
-            result
+    let mut result = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+    for i in (0..usize::BITS - exp.leading_zeros()).rev() {
+        result = poly_square(&result);
+        if (exp >> i) & 1 == 1 {
+            result = mul_any_field(&result, poly);
+        }
+    }
+    result
+}
+
+// ark's product interpolates over a domain the size of the result, which fields
+// without such a subgroup cannot build.
+fn mul_any_field<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> DensePolynomial<F> {
+    let product_len = (a.coeffs.len() + b.coeffs.len()).saturating_sub(1);
+    if GeneralEvaluationDomain::<F>::new(product_len).is_some() {
+        a * b
+    } else {
+        a.naive_mul(b)
+    }
+}
+
+// Below this many coefficients the schoolbook square beats the NTT.
+const SQUARE_NTT_THRESHOLD: usize = 64;
+
+// p², using a_i·a_j = a_j·a_i to roughly halve the schoolbook multiplications,
+// and a single forward NTT for large inputs.
+pub fn poly_square<F: PrimeField>(poly: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let n = poly.coeffs.len();
+    if n == 0 {
+        return DensePolynomial::zero();
+    }
+
+    // fields without a two-adic subgroup of the padded size square by schoolbook
+    let size = (2 * n - 1).next_power_of_two();
+    if n >= SQUARE_NTT_THRESHOLD && F::get_root_of_unity(size as u64).is_some() {
+        let ntt = NttDomain::new(size);
+        let mut evals = coeffs_padded(poly, ntt.size());
+        ntt.forward(&mut evals);
+        for e in evals.iter_mut() {
+            e.square_in_place();
+        }
+        ntt.inverse(&mut evals);
+        return DensePolynomial::from_coefficients_vec(evals);
+    }
+
+    let mut result = vec![F::zero(); 2 * n - 1];
+    for i in 0..n {
+        for j in i + 1..n {
+            result[i + j] += poly.coeffs[i] * poly.coeffs[j];
         }
     }
+    for r in result.iter_mut() {
+        r.double_in_place();
+    }
+    for (i, c) in poly.coeffs.iter().enumerate() {
+        result[2 * i] += c.square();
+    }
+
+    DensePolynomial::from_coefficients_vec(result)
 }
 
 pub fn shift_poly<F: PrimeField>(poly: &DensePolynomial<F>, factor: F) -> DensePolynomial<F> {
@@ -30,10 +89,168 @@ pub fn shift_poly<F: PrimeField>(poly: &DensePolynomial<F>, factor: F) -> DenseP
     DensePolynomial::from_coefficients_vec(new_coeffs)
 }
 
+pub fn is_constant<F: PrimeField>(poly: &DensePolynomial<F>) -> bool {
+    poly.coeffs.len() <= 1
+}
+
+// The constant a degree-0 polynomial collapses to (zero for the zero polynomial).
+pub fn constant_value<F: PrimeField>(poly: &DensePolynomial<F>) -> Option<F> {
+    match poly.coeffs.as_slice() {
+        [] => Some(F::zero()),
+        [c] => Some(*c),
+        _ => None,
+    }
+}
+
+/// The coefficients from the constant term up, with no trailing zeros; empty for
+/// the zero polynomial.
+///
+/// ```
+/// use ark_poly::{DenseUVPolynomial, univariate::DensePolynomial};
+/// use stark::{field::Fq, polynomial::coefficients};
+///
+/// let poly = DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2), Fq::from(0)]);
+/// assert_eq!(coefficients(&poly), &[Fq::from(1), Fq::from(2)]);
+/// assert!(coefficients(&DensePolynomial::<Fq>::from_coefficients_vec(vec![])).is_empty());
+/// ```
+pub fn coefficients<F: PrimeField>(poly: &DensePolynomial<F>) -> &[F] {
+    &poly.coeffs
+}
+
+/// The coefficient of x^i, which is zero for any i past the degree.
+///
+/// ```
+/// use ark_poly::{DenseUVPolynomial, univariate::DensePolynomial};
+/// use stark::{field::Fq, polynomial::coefficient};
+///
+/// // 1 + 2x
+/// let poly = DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2)]);
+/// assert_eq!(coefficient(&poly, 1), Fq::from(2));
+/// assert_eq!(coefficient(&poly, 5), Fq::from(0));
+/// ```
+pub fn coefficient<F: PrimeField>(poly: &DensePolynomial<F>, i: usize) -> F {
+    poly.coeffs.get(i).copied().unwrap_or_else(F::zero)
+}
+
+// Equality of coefficient vectors as polynomials: trailing zeros on either side,
+// e.g. from padding to an NTT size, are ignored.
+pub fn poly_eq_coeffs<F: PrimeField>(a: &[F], b: &[F]) -> bool {
+    let trim = |coeffs: &[F]| {
+        let len = coeffs
+            .iter()
+            .rposition(|c| !c.is_zero())
+            .map_or(0, |i| i + 1);
+        coeffs[..len].to_vec()
+    };
+    trim(a) == trim(b)
+}
+
+// The coefficients zero-padded to exactly `len`, e.g. as NTT input. Never
+// truncates: a polynomial with more coefficients than `len` is a caller bug.
+pub fn coeffs_padded<F: PrimeField>(poly: &DensePolynomial<F>, len: usize) -> Vec<F> {
+    assert!(
+        poly.coeffs.len() <= len,
+        "polynomial has more coefficients than the target length"
+    );
+    let mut coeffs = poly.coeffs.clone();
+    coeffs.resize(len, F::zero());
+    coeffs
+}
+
+// Uniformly random coefficients, except that the leading one is redrawn until it
+// is nonzero, so the degree is exactly `degree`.
+pub fn random_poly<F: PrimeField, R: Rng>(degree: usize, rng: &mut R) -> DensePolynomial<F> {
+    let mut coeffs: Vec<F> = (0..degree).map(|_| F::rand(rng)).collect();
+    let leading = iter::repeat_with(|| F::rand(rng))
+        .find(|c| !c.is_zero())
+        .expect("the iterator is endless");
+    coeffs.push(leading);
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+// Scrubs the coefficients, leaving the zero polynomial. Field elements already
+// implement Zeroize through ark-ff; DensePolynomial is foreign, hence a function.
+#[cfg(feature = "zeroize")]
+pub fn zeroize_poly<F: PrimeField>(poly: &mut DensePolynomial<F>) {
+    use zeroize::Zeroize;
+    poly.coeffs.zeroize();
+}
+
+// P(x + c), as opposed to shift_poly which computes P(c·x).
+pub fn translate_poly<F: PrimeField>(poly: &DensePolynomial<F>, c: F) -> DensePolynomial<F> {
+    if c.is_zero() {
+        return poly.clone();
+    }
+
+    // Horner in polynomial form: result = result * (x + c) + coeff
+    let mut result: Vec<F> = Vec::with_capacity(poly.coeffs.len());
+    for coeff in poly.coeffs.iter().rev() {
+        result.push(F::zero());
+        for i in (1..result.len()).rev() {
+            result[i] = result[i - 1] + c * result[i];
+        }
+        result[0] = c * result[0] + coeff;
+    }
+
+    DensePolynomial::from_coefficients_vec(result)
+}
+
+// Synthetic division by (x - z); the remainder is P(z).
+pub fn divide_by_linear<F: PrimeField>(poly: &DensePolynomial<F>, z: F) -> (DensePolynomial<F>, F) {
+    if poly.coeffs.len() < 2 {
+        let remainder = poly.coeffs.first().copied().unwrap_or_else(F::zero);
+        return (DensePolynomial::from_coefficients_vec(vec![]), remainder);
+    }
+
+    let mut quotient = vec![F::zero(); poly.coeffs.len() - 1];
+    let mut acc = F::zero();
+    for i in (1..poly.coeffs.len()).rev() {
+        acc = poly.coeffs[i] + z * acc;
+        quotient[i - 1] = acc;
+    }
+    let remainder = poly.coeffs[0] + z * acc;
+
+    (DensePolynomial::from_coefficients_vec(quotient), remainder)
+}
+
+/// Whether all `(x, y)` points lie on one line, the check a FRI verifier runs on
+/// `(ω, f(ω))`, `(-ω, f(-ω))` and `(r, f_folded(ω²))`.
+///
+/// Compares cross products, so no division is needed:
+///
+/// ```
+/// use stark::{field::Fq, polynomial::test_colinearity};
+///
+/// // y = 2x + 1
+/// let line = [(1, 3), (2, 5), (10, 21)].map(|(x, y)| (Fq::from(x), Fq::from(y)));
+/// assert!(test_colinearity(&line));
+///
+/// let bent = [(1, 3), (2, 5), (10, 22)].map(|(x, y)| (Fq::from(x), Fq::from(y)));
+/// assert!(!test_colinearity(&bent));
+/// ```
+pub fn test_colinearity<F: PrimeField>(points: &[(F, F)]) -> bool {
+    let [(x0, y0), (x1, y1), rest @ ..] = points else {
+        return true;
+    };
+    rest.iter()
+        .all(|(x, y)| (*x1 - x0) * (*y - y0) == (*y1 - y0) * (*x - x0))
+}
+
 pub fn domain<F: FftField>(size: usize) -> GeneralEvaluationDomain<F> {
     GeneralEvaluationDomain::new(size).expect("failed to create evaluation domain")
 }
 
+// Yields 1, g, g^2, ..., g^(n-1) for a primitive n-th root of unity g.
+pub fn subgroup<F: FftField>(n: usize) -> impl Iterator<Item = F> {
+    let g = F::get_root_of_unity(n as u64).expect("field has no subgroup of this order");
+    iter::successors(Some(F::one()), move |x| Some(*x * g)).take(n)
+}
+
+pub fn coset<F: FftField>(shift: F, n: usize) -> Vec<F> {
+    assert!(!shift.is_zero(), "coset shift must be nonzero");
+    subgroup(n).map(|x: F| shift * x).collect()
+}
+
 pub fn fast_evaluate_domain<F: PrimeField>(
     poly: &DensePolynomial<F>,
     domain: &GeneralEvaluationDomain<F>,
@@ -49,6 +266,29 @@ pub fn fast_interpolate<F: PrimeField>(
     Evaluations::from_vec_and_domain(values, *domain).interpolate()
 }
 
+// Interpolates every column over the subgroup of their common power-of-two
+// length, sharing one set of inverse twiddles across all of them.
+pub fn interpolate_columns<F: PrimeField>(columns: &[Vec<F>]) -> Vec<DensePolynomial<F>> {
+    let Some(first) = columns.first() else {
+        return Vec::new();
+    };
+    let n = first.len();
+    assert!(
+        n.is_power_of_two() && columns.iter().all(|column| column.len() == n),
+        "columns must all have the same power-of-two length"
+    );
+    let ntt = NttDomain::new(n);
+
+    columns
+        .iter()
+        .map(|column| {
+            let mut coeffs = column.clone();
+            ntt.inverse(&mut coeffs);
+            DensePolynomial::from_coefficients_vec(coeffs)
+        })
+        .collect()
+}
+
 pub fn coset_evaluate<F: PrimeField>(
     poly: &DensePolynomial<F>,
     coset_domain: &GeneralEvaluationDomain<F>,
@@ -56,16 +296,128 @@ pub fn coset_evaluate<F: PrimeField>(
     coset_domain.fft(&poly.coeffs)
 }
 
+// Number of points evaluate_points runs through Horner together; 8 Goldilocks
+// elements fill a 512-bit vector register.
+const HORNER_LANES: usize = 8;
+
+// Same values as mapping poly.evaluate over `points`, but each coefficient is
+// applied to a fixed-size block of independent accumulators, which the compiler
+// can keep in registers and vectorize.
+pub fn evaluate_points<F: PrimeField>(poly: &DensePolynomial<F>, points: &[F]) -> Vec<F> {
+    let mut evals = Vec::with_capacity(points.len());
+    for chunk in points.chunks(HORNER_LANES) {
+        let mut acc = [F::zero(); HORNER_LANES];
+        for coeff in poly.coeffs.iter().rev() {
+            for (a, x) in acc.iter_mut().zip(chunk) {
+                *a = *a * x + coeff;
+            }
+        }
+        evals.extend_from_slice(&acc[..chunk.len()]);
+    }
+    evals
+}
+
+// Point-by-point Horner over shift·g^i, i < n. O(n · deg); a reference for
+// coset_evaluate.
+pub fn evaluate_coset_naive<F: PrimeField>(
+    poly: &DensePolynomial<F>,
+    shift: F,
+    generator: F,
+    n: usize,
+) -> Vec<F> {
+    iter::successors(Some(shift), |x| Some(*x * generator))
+        .take(n)
+        .map(|x| poly.evaluate(&x))
+        .collect()
+}
+
 pub fn fast_vanishing_poly<F: PrimeField>(n: usize) -> SparsePolynomial<F> {
     SparsePolynomial::from_coefficients_vec(vec![(0, -F::one()), (n, F::one())])
 }
 
+// Dense times sparse in O(len · terms), e.g. multiplying by x^n - 1.
+pub fn mul_sparse<F: PrimeField>(
+    dense: &DensePolynomial<F>,
+    sparse: &SparsePolynomial<F>,
+) -> DensePolynomial<F> {
+    let Some(max_power) = sparse.iter().map(|(power, _)| *power).max() else {
+        return DensePolynomial::zero();
+    };
+    if dense.is_zero() {
+        return DensePolynomial::zero();
+    }
+
+    let mut result = vec![F::zero(); dense.coeffs.len() + max_power];
+    for (power, coeff) in sparse.iter() {
+        for (r, c) in result[*power..].iter_mut().zip(&dense.coeffs) {
+            *r += *coeff * c;
+        }
+    }
+    DensePolynomial::from_coefficients_vec(result)
+}
+
+pub fn vanishing_eval<F: PrimeField>(n: usize, x: F) -> F {
+    x.pow([n as u64]) - F::one()
+}
+
+// x^n - shift^n, vanishing exactly on the coset shift·<g> with |<g>| = n.
+pub fn coset_vanishing<F: PrimeField>(shift: F, n: usize) -> SparsePolynomial<F> {
+    SparsePolynomial::from_coefficients_vec(vec![(0, -shift.pow([n as u64])), (n, F::one())])
+}
+
+pub fn coset_vanishing_eval<F: PrimeField>(shift: F, n: usize, x: F) -> F {
+    x.pow([n as u64]) - shift.pow([n as u64])
+}
+
+// x^n - 1 over shift·<g>, where g generates the subgroup of order `size` (a multiple
+// of n). Since (shift·g^i)^n = shift^n·(g^n)^i, only size / n distinct values occur.
+pub fn vanishing_evals_on_coset<F: PrimeField>(n: usize, shift: F, size: usize) -> Vec<F> {
+    assert!(
+        size.is_multiple_of(n),
+        "subgroup order must divide the coset size"
+    );
+
+    let period = size / n;
+    let g_n = F::get_root_of_unity(size as u64)
+        .expect("field has no subgroup of this order")
+        .pow([n as u64]);
+
+    let mut cycle = Vec::with_capacity(period);
+    let mut acc = shift.pow([n as u64]);
+    for _ in 0..period {
+        cycle.push(acc - F::one());
+        acc *= g_n;
+    }
+
+    (0..size).map(|i| cycle[i % period]).collect()
+}
+
+// Quotient by x^n - 1 in evaluation form, for `evals` taken over shift·<g> with
+// |<g>| = evals.len(). The coset must not meet the order-n subgroup.
+pub fn divide_evaluations_by_vanishing<F: PrimeField>(evals: &[F], shift: F, n: usize) -> Vec<F> {
+    let mut denominators = vanishing_evals_on_coset(n, shift, evals.len());
+    assert!(
+        denominators.iter().all(|d| !d.is_zero()),
+        "coset intersects the vanishing subgroup"
+    );
+    batch_inversion(&mut denominators);
+
+    evals
+        .iter()
+        .zip(denominators)
+        .map(|(eval, inv)| *eval * inv)
+        .collect()
+}
+
 pub fn divide_by_vanishing<F: PrimeField>(
     poly: &DensePolynomial<F>,
     domain: &GeneralEvaluationDomain<F>,
 ) -> DensePolynomial<F> {
     let n = domain.size();
     let deg = poly.degree();
+    if poly.is_zero() || deg < n {
+        return DensePolynomial::zero();
+    }
 
     let mut q_coeffs = vec![F::zero(); deg - n + 1];
     let mut coeffs = poly.coeffs.clone();
@@ -77,3 +429,427 @@ pub fn divide_by_vanishing<F: PrimeField>(
 
     DensePolynomial::from_coefficients_vec(q_coeffs)
 }
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{FftField, Field};
+    use ark_std::collections::BTreeSet;
+
+    use super::*;
+    use crate::field::Fq;
+
+    #[test]
+    fn test_subgroup() {
+        let n = 16;
+        let elements: Vec<Fq> = subgroup(n).collect();
+        assert_eq!(elements.len(), n);
+        assert_eq!(elements.iter().collect::<BTreeSet<_>>().len(), n);
+
+        // the product of all n-th roots of unity is (-1)^(n+1)
+        let product: Fq = elements.iter().product();
+        assert_eq!(product, -Fq::from(1));
+        assert!(elements.iter().all(|x| x.pow([n as u64]) == Fq::from(1)));
+    }
+
+    #[test]
+    fn test_coset_disjoint_from_subgroup() {
+        let n = 16;
+        let base: BTreeSet<Fq> = subgroup(n).collect();
+        let shifted = coset(Fq::GENERATOR, n);
+
+        assert_eq!(shifted.len(), n);
+        assert_eq!(shifted.iter().collect::<BTreeSet<_>>().len(), n);
+        assert!(shifted.iter().all(|x| !base.contains(x)));
+    }
+
+    #[test]
+    fn test_coefficient_indexing_and_iteration() {
+        // DensePolynomial derefs to its coefficient slice, lowest degree first
+        let poly =
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2), Fq::from(3)]);
+        assert_eq!(poly[0], Fq::from(1));
+        assert_eq!(poly[2], Fq::from(3));
+        assert_eq!(poly.iter().sum::<Fq>(), Fq::from(6));
+        assert_eq!(
+            poly.coeffs.into_iter().collect::<Vec<_>>(),
+            vec![Fq::from(1), Fq::from(2), Fq::from(3)]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_coefficient_index_out_of_range() {
+        let poly = DensePolynomial::from_coefficients_vec(vec![Fq::from(1)]);
+        let _ = poly[1];
+    }
+
+    #[test]
+    fn test_translate_poly() {
+        let poly = DensePolynomial::from_coefficients_vec(vec![
+            Fq::from(5),
+            Fq::from(0),
+            Fq::from(3),
+            Fq::from(7),
+        ]);
+        let c = Fq::from(11);
+        let translated = translate_poly(&poly, c);
+
+        assert_eq!(translated.degree(), poly.degree());
+        for x in [0u64, 1, 2, 1000] {
+            let x = Fq::from(x);
+            assert_eq!(translated.evaluate(&x), poly.evaluate(&(x + c)));
+        }
+        assert_eq!(translate_poly(&poly, Fq::from(0)), poly);
+    }
+
+    #[test]
+    fn test_sparse_and_dense_evaluations_agree() {
+        let sparse = SparsePolynomial::from_coefficients_vec(vec![
+            (0, Fq::from(3)),
+            (5, Fq::from(2)),
+            (64, -Fq::from(1)),
+        ]);
+        let dense: DensePolynomial<Fq> = sparse.clone().into();
+
+        assert_eq!(dense.degree(), 64);
+        for x in [0u64, 1, 9, 1 << 40] {
+            let x = Fq::from(x);
+            assert_eq!(sparse.evaluate(&x), dense.evaluate(&x));
+        }
+    }
+
+    #[test]
+    fn test_fast_vanishing_poly() {
+        let n = 32;
+        let vanishing = fast_vanishing_poly::<Fq>(n);
+
+        assert_eq!(vanishing.len(), 2);
+        assert!(subgroup::<Fq>(n).all(|x| vanishing.evaluate(&x).is_zero()));
+        assert!(!vanishing.evaluate(&Fq::GENERATOR).is_zero());
+    }
+
+    #[test]
+    fn test_evaluate_points_matches_evaluate() {
+        let poly = DensePolynomial::from_coefficients_vec(
+            (0..9u64).map(|i| Fq::from(i * i + 3)).collect(),
+        );
+        // 19 points: two full chunks and a partial one
+        let points: Vec<Fq> = (0..19u64).map(|i| Fq::from(i * 101)).collect();
+        let expected: Vec<Fq> = points.iter().map(|x| poly.evaluate(x)).collect();
+
+        assert_eq!(evaluate_points(&poly, &points), expected);
+        assert!(evaluate_points(&poly, &[]).is_empty());
+        assert!(
+            evaluate_points(&DensePolynomial::zero(), &points)
+                .iter()
+                .all(Zero::is_zero)
+        );
+    }
+
+    #[test]
+    fn test_interpolate_columns() {
+        let domain = domain::<Fq>(16);
+        let columns: Vec<Vec<Fq>> = (0..5u64)
+            .map(|c| (0..16u64).map(|i| Fq::from(c * 1000 + i * i)).collect())
+            .collect();
+
+        let polys = interpolate_columns(&columns);
+        assert_eq!(polys.len(), columns.len());
+        for (poly, column) in polys.iter().zip(&columns) {
+            assert_eq!(*poly, fast_interpolate(column.clone(), &domain));
+        }
+        assert!(interpolate_columns::<Fq>(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "columns must all have the same power-of-two length")]
+    fn test_interpolate_columns_ragged() {
+        interpolate_columns(&[vec![Fq::from(1); 8], vec![Fq::from(1); 4]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "columns must all have the same power-of-two length")]
+    fn test_interpolate_columns_not_power_of_two() {
+        interpolate_columns(&[vec![Fq::from(1); 6]]);
+    }
+
+    #[test]
+    fn test_evaluate_coset_naive_matches_ntt() {
+        let poly = DensePolynomial::from_coefficients_vec(
+            (0..13u64).map(|i| Fq::from(i * 7 + 2)).collect(),
+        );
+        let shift = Fq::GENERATOR;
+        let size = 32;
+        let coset_domain = domain::<Fq>(size).get_coset(shift).unwrap();
+
+        assert_eq!(
+            evaluate_coset_naive(&poly, shift, coset_domain.group_gen(), size),
+            coset_evaluate(&poly, &coset_domain)
+        );
+    }
+
+    #[test]
+    fn test_mul_sparse() {
+        let dense = DensePolynomial::from_coefficients_vec(
+            (1..20u64).map(|i| Fq::from(i * 3 + 1)).collect(),
+        );
+        let sparse = SparsePolynomial::from_coefficients_vec(vec![
+            (0, -Fq::from(1)),
+            (5, Fq::from(2)),
+            (16, Fq::from(1)),
+        ]);
+
+        let expected = &dense * &DensePolynomial::from(sparse.clone());
+        assert_eq!(mul_sparse(&dense, &sparse), expected);
+        assert_eq!(
+            mul_sparse(&dense, &fast_vanishing_poly(8)),
+            &dense * &DensePolynomial::from(fast_vanishing_poly(8))
+        );
+        assert!(mul_sparse(&DensePolynomial::zero(), &sparse).is_zero());
+        assert!(mul_sparse(&dense, &SparsePolynomial::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_coset_vanishing() {
+        let n = 8;
+        let shift = Fq::GENERATOR;
+        let z = coset_vanishing(shift, n);
+
+        for x in coset(shift, n) {
+            assert!(z.evaluate(&x).is_zero());
+            assert!(coset_vanishing_eval(shift, n, x).is_zero());
+        }
+        for x in subgroup::<Fq>(n) {
+            assert!(!z.evaluate(&x).is_zero());
+            assert_eq!(z.evaluate(&x), coset_vanishing_eval(shift, n, x));
+        }
+
+        // the trivial coset gives back x^n - 1
+        assert_eq!(coset_vanishing(Fq::from(1), n), fast_vanishing_poly(n));
+    }
+
+    #[test]
+    fn test_vanishing_evals_on_coset() {
+        let n = 8;
+        let size = 32;
+        let shift = Fq::GENERATOR;
+        let dense: DensePolynomial<Fq> = fast_vanishing_poly(n).into();
+
+        let evals = vanishing_evals_on_coset(n, shift, size);
+        let points = coset(shift, size);
+        assert_eq!(evals.len(), size);
+        for (x, eval) in points.iter().zip(&evals) {
+            assert_eq!(*eval, dense.evaluate(x));
+            assert_eq!(*eval, vanishing_eval(n, *x));
+        }
+
+        let on_subgroup = vanishing_evals_on_coset::<Fq>(n, Fq::from(1), n);
+        assert!(on_subgroup.iter().all(|x| x.is_zero()));
+    }
+
+    #[test]
+    fn test_divide_by_linear() {
+        // (x^2 + 3x + 5) = (x - 2)(x + 5) + 15
+        let poly =
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(5), Fq::from(3), Fq::from(1)]);
+        let (quotient, remainder) = divide_by_linear(&poly, Fq::from(2));
+
+        assert_eq!(
+            quotient,
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(5), Fq::from(1)])
+        );
+        assert_eq!(remainder, Fq::from(15));
+        assert_eq!(remainder, poly.evaluate(&Fq::from(2)));
+
+        let (quotient, remainder) = divide_by_linear(&DensePolynomial::zero(), Fq::from(2));
+        assert!(quotient.is_zero() && remainder.is_zero());
+    }
+
+    #[test]
+    fn test_divide_evaluations_by_vanishing() {
+        let n = 8;
+        let size = 32;
+        let shift = Fq::GENERATOR;
+        let quotient =
+            DensePolynomial::from_coefficients_vec((1..=20).map(|i| Fq::from(i as u64)).collect());
+        let poly = quotient.mul_by_vanishing_poly(domain::<Fq>(n));
+
+        let coset_domain = domain::<Fq>(size).get_coset(shift).unwrap();
+        let evals = coset_evaluate(&poly, &coset_domain);
+        let quotient_evals = divide_evaluations_by_vanishing(&evals, shift, n);
+
+        let recovered = DensePolynomial::from_coefficients_vec(coset_domain.ifft(&quotient_evals));
+        assert_eq!(recovered, quotient);
+    }
+
+    #[test]
+    #[should_panic(expected = "coset intersects the vanishing subgroup")]
+    fn test_divide_evaluations_by_vanishing_on_subgroup() {
+        divide_evaluations_by_vanishing(&[Fq::from(1); 8], Fq::from(1), 8);
+    }
+
+    #[test]
+    fn test_poly_square() {
+        let sparse = DensePolynomial::from_coefficients_vec(vec![
+            Fq::from(3),
+            Fq::from(0),
+            Fq::from(0),
+            -Fq::from(1),
+        ]);
+        assert_eq!(poly_square(&sparse), &sparse * &sparse);
+
+        // large enough to take the NTT path
+        let dense = DensePolynomial::from_coefficients_vec(
+            (0..100u64).map(|i| Fq::from(i * i + 1)).collect(),
+        );
+        assert_eq!(poly_square(&dense), &dense * &dense);
+        assert!(poly_square(&DensePolynomial::<Fq>::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_poly_pow() {
+        let poly = DensePolynomial::from_coefficients_vec(vec![Fq::from(2), Fq::from(1)]);
+        let mut expected = DensePolynomial::from_coefficients_vec(vec![Fq::from(1)]);
+        for exp in 0..10 {
+            assert_eq!(poly_pow(&poly, exp), expected);
+            expected = &expected * &poly;
+        }
+    }
+
+    #[test]
+    fn test_poly_pow_without_large_subgroup() {
+        use crate::field::test_fields::Mersenne61;
+
+        assert!(Mersenne61::get_root_of_unity(4).is_none());
+
+        let poly = DensePolynomial::from_coefficients_vec(
+            (0..100u64).map(|i| Mersenne61::from(i * i + 1)).collect(),
+        );
+        let square = poly.naive_mul(&poly);
+        assert_eq!(poly_square(&poly), square);
+        assert_eq!(poly_pow(&poly, 3), square.naive_mul(&poly));
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut x = Fq::from(42);
+        x.zeroize();
+        assert!(x.is_zero());
+
+        let mut poly = DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2)]);
+        zeroize_poly(&mut poly);
+        assert!(poly.is_zero());
+        assert!(poly.coeffs.is_empty());
+    }
+
+    #[test]
+    fn test_poly_eq_coeffs() {
+        let one_two = [Fq::from(1), Fq::from(2)];
+        let padded = [Fq::from(1), Fq::from(2), Fq::zero(), Fq::zero()];
+        assert!(poly_eq_coeffs(&one_two, &padded));
+        assert!(poly_eq_coeffs(&padded, &one_two));
+        assert!(poly_eq_coeffs::<Fq>(&[], &[Fq::zero(); 3]));
+
+        assert!(!poly_eq_coeffs(&one_two, &[Fq::from(1)]));
+        assert!(!poly_eq_coeffs(
+            &one_two,
+            &[Fq::zero(), Fq::from(1), Fq::from(2)]
+        ));
+
+        // an NTT round trip comes back padded to the transform size
+        let ntt = NttDomain::new(8);
+        let mut values = coeffs_padded(&DensePolynomial::from_coefficients_slice(&one_two), 8);
+        ntt.forward(&mut values);
+        ntt.inverse(&mut values);
+        assert_eq!(values.len(), 8);
+        assert!(poly_eq_coeffs(&values, &one_two));
+    }
+
+    #[test]
+    fn test_random_poly() {
+        let rng = &mut ark_std::test_rng();
+        for degree in [0, 1, 7, 64] {
+            let poly = random_poly::<Fq, _>(degree, rng);
+            assert_eq!(poly.degree(), degree);
+            assert_eq!(poly.coeffs.len(), degree + 1);
+            assert!(!poly.coeffs[degree].is_zero());
+        }
+        assert_ne!(random_poly::<Fq, _>(5, rng), random_poly::<Fq, _>(5, rng));
+    }
+
+    #[test]
+    fn test_coeffs_padded() {
+        let poly = DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2)]);
+        assert_eq!(
+            coeffs_padded(&poly, 4),
+            vec![Fq::from(1), Fq::from(2), Fq::zero(), Fq::zero()]
+        );
+        assert_eq!(coeffs_padded(&poly, 2), poly.coeffs);
+        assert_eq!(
+            coeffs_padded(&DensePolynomial::<Fq>::zero(), 3),
+            vec![Fq::zero(); 3]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "more coefficients than the target length")]
+    fn test_coeffs_padded_too_short() {
+        let poly = DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2)]);
+        coeffs_padded(&poly, 1);
+    }
+
+    #[test]
+    fn test_constant_value() {
+        let zero = DensePolynomial::<Fq>::zero();
+        assert!(is_constant(&zero));
+        assert_eq!(constant_value(&zero), Some(Fq::zero()));
+
+        let five = DensePolynomial::from_coefficients_vec(vec![Fq::from(5)]);
+        assert!(is_constant(&five));
+        assert_eq!(constant_value(&five), Some(Fq::from(5)));
+
+        let linear = DensePolynomial::from_coefficients_vec(vec![Fq::from(5), Fq::from(1)]);
+        assert!(!is_constant(&linear));
+        assert_eq!(constant_value(&linear), None);
+    }
+
+    #[test]
+    fn test_zero_polynomial_inputs() {
+        let zero = DensePolynomial::<Fq>::zero();
+        let x = Fq::from(5);
+
+        assert!(zero.evaluate(&x).is_zero());
+        assert!(shift_poly(&zero, x).is_zero());
+        assert!(translate_poly(&zero, x).is_zero());
+        assert!(poly_pow(&zero, 3).is_zero());
+        assert_eq!(
+            poly_pow(&zero, 0),
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(1)])
+        );
+        assert_eq!(divide_by_linear(&zero, x), (zero.clone(), Fq::zero()));
+        assert!(
+            fast_evaluate_domain(&zero, &domain(4))
+                .iter()
+                .all(Zero::is_zero)
+        );
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_low_degree() {
+        let domain = domain(8);
+        assert!(divide_by_vanishing(&DensePolynomial::<Fq>::zero(), &domain).is_zero());
+
+        // deg < n leaves everything in the remainder
+        let poly = DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2)]);
+        assert!(divide_by_vanishing(&poly, &domain).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "coset shift must be nonzero")]
+    fn test_coset_zero_shift() {
+        coset(Fq::from(0), 4);
+    }
+}
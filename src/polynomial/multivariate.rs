@@ -0,0 +1,638 @@
+use ark_ff::PrimeField;
+use ark_poly::{
+    DenseMVPolynomial, DenseUVPolynomial,
+    multivariate::{SparsePolynomial, SparseTerm, Term},
+    univariate::DensePolynomial,
+};
+use ark_std::{collections::BTreeMap, vec, vec::Vec};
+
+use crate::{error::MPolyError, polynomial::poly_pow};
+
+// Like Polynomial::evaluate, but reports a point that is too short instead of
+// panicking. Only the variables the terms actually use are required, so the zero
+// polynomial evaluates to zero at any point, including the empty one.
+pub fn try_evaluate<F: PrimeField>(
+    poly: &SparsePolynomial<F, SparseTerm>,
+    point: &[F],
+) -> Result<F, MPolyError> {
+    let required = poly
+        .terms
+        .iter()
+        .flat_map(|(_, term)| term.iter().map(|(var, _)| var + 1))
+        .max()
+        .unwrap_or(0);
+    if point.len() < required {
+        return Err(MPolyError::PointTooShort {
+            required,
+            got: point.len(),
+        });
+    }
+
+    Ok(poly
+        .terms
+        .iter()
+        .map(|(coeff, term)| {
+            term.iter().fold(*coeff, |acc, (var, power)| {
+                acc * point[*var].pow([*power as u64])
+            })
+        })
+        .sum())
+}
+
+// powers[i][e] = point[i]^e for e up to max_degree, so many polynomials can be
+// evaluated at the same point without redoing the exponentiations.
+pub fn power_table<F: PrimeField>(point: &[F], max_degree: usize) -> Vec<Vec<F>> {
+    point
+        .iter()
+        .map(|x| {
+            ark_std::iter::successors(Some(F::one()), |p| Some(*p * x))
+                .take(max_degree + 1)
+                .collect()
+        })
+        .collect()
+}
+
+// Same value as evaluate at the point the table was built from. The table must
+// reach every variable and exponent the terms use.
+pub fn evaluate_with_powers<F: PrimeField>(
+    poly: &SparsePolynomial<F, SparseTerm>,
+    powers: &[Vec<F>],
+) -> F {
+    poly.terms
+        .iter()
+        .map(|(coeff, term)| {
+            term.iter()
+                .fold(*coeff, |acc, (var, power)| acc * powers[*var][*power])
+        })
+        .sum()
+}
+
+// The univariate polynomial obtained by substituting substitutions[i] for x_i,
+// e.g. trace polynomials into a transition constraint.
+pub fn evaluate_symbolic<F: PrimeField>(
+    poly: &SparsePolynomial<F, SparseTerm>,
+    substitutions: &[DensePolynomial<F>],
+) -> DensePolynomial<F> {
+    let mut result = DensePolynomial::from_coefficients_vec(vec![]);
+    for (coeff, term) in &poly.terms {
+        let mut monomial = DensePolynomial::from_coefficients_vec(vec![*coeff]);
+        for (var, power) in term.iter() {
+            monomial = &monomial * &poly_pow(&substitutions[*var], *power);
+        }
+        result += &monomial;
+    }
+    result
+}
+
+// Product of two polynomials. Errors instead of wrapping if the exponents of a
+// variable sum past usize::MAX, which untrusted deserialized inputs could trigger.
+pub fn checked_mul<F: PrimeField>(
+    a: &SparsePolynomial<F, SparseTerm>,
+    b: &SparsePolynomial<F, SparseTerm>,
+) -> Result<SparsePolynomial<F, SparseTerm>, MPolyError> {
+    let mut terms = Vec::with_capacity(a.terms.len() * b.terms.len());
+    for (ca, ta) in &a.terms {
+        for (cb, tb) in &b.terms {
+            terms.push((*ca * cb, checked_mul_terms(ta, tb)?));
+        }
+    }
+
+    Ok(SparsePolynomial::from_coefficients_vec(
+        a.num_vars.max(b.num_vars),
+        terms,
+    ))
+}
+
+// poly^exponent by square-and-multiply, giving up as soon as an intermediate
+// result has more than max_terms terms, before the next product can grow it
+// further. Meant for constraint powers that come from untrusted input.
+pub fn try_pow<F: PrimeField>(
+    poly: &SparsePolynomial<F, SparseTerm>,
+    exponent: usize,
+    max_terms: usize,
+) -> Result<SparsePolynomial<F, SparseTerm>, MPolyError> {
+    let guard = |p: SparsePolynomial<F, SparseTerm>| match p.terms.len() > max_terms {
+        true => Err(MPolyError::TooManyTerms { limit: max_terms }),
+        false => Ok(p),
+    };
+
+    let mut result = guard(SparsePolynomial::from_coefficients_vec(
+        poly.num_vars,
+        vec![(F::one(), SparseTerm::new(vec![]))],
+    ))?;
+    for i in (0..usize::BITS - exponent.leading_zeros()).rev() {
+        result = guard(checked_mul(&result, &result)?)?;
+        if (exponent >> i) & 1 == 1 {
+            result = guard(checked_mul(&result, poly)?)?;
+        }
+    }
+    Ok(result)
+}
+
+// Renames x_i to x_{mapping[i]}. Mapping two variables to the same index
+// substitutes one for the other, so exponents of merged variables add up.
+pub fn permute_variables<F: PrimeField>(
+    poly: &SparsePolynomial<F, SparseTerm>,
+    mapping: &[usize],
+) -> SparsePolynomial<F, SparseTerm> {
+    assert!(
+        mapping.len() >= poly.num_vars,
+        "mapping must cover every variable of the polynomial"
+    );
+
+    let num_vars = mapping[..poly.num_vars]
+        .iter()
+        .max()
+        .map_or(0, |max| max + 1);
+    let terms = poly
+        .terms
+        .iter()
+        .map(|(coeff, term)| {
+            let renamed = term.iter().map(|(var, power)| (mapping[*var], *power));
+            (*coeff, SparseTerm::new(renamed.collect()))
+        })
+        .collect();
+
+    SparsePolynomial::from_coefficients_vec(num_vars, terms)
+}
+
+// P(x_var) as a polynomial in num_vars variables.
+pub fn embed<F: PrimeField>(
+    poly: &DensePolynomial<F>,
+    var: usize,
+    num_vars: usize,
+) -> SparsePolynomial<F, SparseTerm> {
+    assert!(var < num_vars, "variable index out of range");
+
+    let terms = poly
+        .coeffs
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !c.is_zero())
+        .map(|(i, c)| (*c, SparseTerm::new(vec![(var, i)])))
+        .collect();
+    SparsePolynomial::from_coefficients_vec(num_vars, terms)
+}
+
+// Applies `f` to every coefficient; terms that map to zero are dropped.
+pub fn map_coefficients<F: PrimeField>(
+    poly: &SparsePolynomial<F, SparseTerm>,
+    f: impl Fn(F) -> F,
+) -> SparsePolynomial<F, SparseTerm> {
+    let terms = poly
+        .terms
+        .iter()
+        .map(|(coeff, term)| (f(*coeff), term.clone()))
+        .collect();
+    SparsePolynomial::from_coefficients_vec(poly.num_vars, terms)
+}
+
+// Terms are kept sorted and merged, so a constant has at most the one empty term.
+pub fn is_constant<F: PrimeField>(poly: &SparsePolynomial<F, SparseTerm>) -> bool {
+    as_constant(poly).is_some()
+}
+
+// The value of a constant polynomial (zero for the zero polynomial).
+pub fn as_constant<F: PrimeField>(poly: &SparsePolynomial<F, SparseTerm>) -> Option<F> {
+    match poly.terms.as_slice() {
+        [] => Some(F::zero()),
+        [(c, term)] if term.is_constant() => Some(*c),
+        _ => None,
+    }
+}
+
+// Groups the terms by total degree; summing the components gives back `poly`.
+pub fn homogeneous_components<F: PrimeField>(
+    poly: &SparsePolynomial<F, SparseTerm>,
+) -> BTreeMap<usize, SparsePolynomial<F, SparseTerm>> {
+    let mut groups: BTreeMap<usize, Vec<(F, SparseTerm)>> = BTreeMap::new();
+    for (coeff, term) in &poly.terms {
+        groups
+            .entry(term.degree())
+            .or_default()
+            .push((*coeff, term.clone()));
+    }
+
+    groups
+        .into_iter()
+        .map(|(degree, terms)| {
+            (
+                degree,
+                SparsePolynomial::from_coefficients_vec(poly.num_vars, terms),
+            )
+        })
+        .collect()
+}
+
+fn checked_mul_terms(a: &SparseTerm, b: &SparseTerm) -> Result<SparseTerm, MPolyError> {
+    // Both sides are sorted by variable with no duplicates, so merge them
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        let next = match (a.get(i), b.get(j)) {
+            (Some(x), Some(y)) if x.0 == y.0 => {
+                i += 1;
+                j += 1;
+                let power =
+                    x.1.checked_add(y.1)
+                        .ok_or(MPolyError::ExponentOverflow { var: x.0 })?;
+                (x.0, power)
+            }
+            (Some(x), Some(y)) if x.0 < y.0 => {
+                i += 1;
+                *x
+            }
+            (Some(x), None) => {
+                i += 1;
+                *x
+            }
+            (_, Some(y)) => {
+                j += 1;
+                *y
+            }
+            (None, None) => unreachable!(),
+        };
+        merged.push(next);
+    }
+
+    Ok(SparseTerm::new(merged))
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::Zero;
+    use ark_poly::{DenseUVPolynomial, Polynomial};
+
+    use super::*;
+    use crate::field::Fq;
+
+    #[test]
+    fn test_try_evaluate_matches_evaluate() {
+        // 2·x_0^3 + x_0·x_2 + 5
+        let poly = SparsePolynomial::from_coefficients_vec(
+            3,
+            vec![
+                (Fq::from(2), SparseTerm::new(vec![(0, 3)])),
+                (Fq::from(1), SparseTerm::new(vec![(0, 1), (2, 1)])),
+                (Fq::from(5), SparseTerm::new(vec![])),
+            ],
+        );
+        let point = vec![Fq::from(3), Fq::from(4), Fq::from(5)];
+
+        assert_eq!(try_evaluate(&poly, &point).unwrap(), poly.evaluate(&point));
+        assert_eq!(try_evaluate(&poly, &point).unwrap(), Fq::from(74));
+    }
+
+    #[test]
+    fn test_evaluate_with_powers_matches_evaluate() {
+        // 2·x_0^3 + x_0·x_2 + 5 and x_1^2·x_2 - x_0
+        let polys = [
+            SparsePolynomial::from_coefficients_vec(
+                3,
+                vec![
+                    (Fq::from(2), SparseTerm::new(vec![(0, 3)])),
+                    (Fq::from(1), SparseTerm::new(vec![(0, 1), (2, 1)])),
+                    (Fq::from(5), SparseTerm::new(vec![])),
+                ],
+            ),
+            SparsePolynomial::from_coefficients_vec(
+                3,
+                vec![
+                    (Fq::from(1), SparseTerm::new(vec![(1, 2), (2, 1)])),
+                    (-Fq::from(1), SparseTerm::new(vec![(0, 1)])),
+                ],
+            ),
+        ];
+        let point = vec![Fq::from(3), Fq::from(7), Fq::from(11)];
+        let powers = power_table(&point, 3);
+
+        assert_eq!(powers[1], [1u64, 7, 49, 343].map(Fq::from));
+        for poly in &polys {
+            assert_eq!(evaluate_with_powers(poly, &powers), poly.evaluate(&point));
+        }
+    }
+
+    #[test]
+    fn test_serialization_is_order_independent() {
+        use ark_serialize::CanonicalSerialize;
+
+        let terms = vec![
+            (Fq::from(2), SparseTerm::new(vec![(0, 3)])),
+            (Fq::from(1), SparseTerm::new(vec![(2, 1), (0, 1)])),
+            (Fq::from(5), SparseTerm::new(vec![])),
+            (Fq::from(4), SparseTerm::new(vec![(1, 2)])),
+        ];
+        let forward = SparsePolynomial::from_coefficients_vec(3, terms.clone());
+        let reversed =
+            SparsePolynomial::from_coefficients_vec(3, terms.iter().rev().cloned().collect());
+        // built by addition, with the x_1^2 coefficient split over two terms
+        let mut split = terms.clone();
+        split[3].0 = Fq::from(1);
+        split.push((Fq::from(3), SparseTerm::new(vec![(1, 2)])));
+        let summed = split
+            .into_iter()
+            .map(|term| SparsePolynomial::from_coefficients_vec(3, vec![term]))
+            .fold(SparsePolynomial::zero(), |acc, p| acc + p);
+
+        let bytes = |p: &SparsePolynomial<Fq, SparseTerm>| {
+            let mut bytes = Vec::new();
+            p.serialize_compressed(&mut bytes).unwrap();
+            bytes
+        };
+        assert_eq!(bytes(&forward), bytes(&reversed));
+        assert_eq!(bytes(&forward), bytes(&summed));
+    }
+
+    #[test]
+    fn test_map_coefficients() {
+        // 2·x_0^3 + x_0·x_2 + 5
+        let poly = SparsePolynomial::from_coefficients_vec(
+            3,
+            vec![
+                (Fq::from(2), SparseTerm::new(vec![(0, 3)])),
+                (Fq::from(1), SparseTerm::new(vec![(0, 1), (2, 1)])),
+                (Fq::from(5), SparseTerm::new(vec![])),
+            ],
+        );
+        let seven = SparsePolynomial::from_coefficients_vec(
+            3,
+            vec![(Fq::from(7), SparseTerm::new(vec![]))],
+        );
+        assert_eq!(
+            map_coefficients(&poly, |c| c * Fq::from(7)),
+            checked_mul(&poly, &seven).unwrap()
+        );
+
+        // coefficients sent to zero disappear
+        let dropped = map_coefficients(&poly, |c| if c == Fq::from(1) { Fq::zero() } else { c });
+        assert_eq!(dropped.terms.len(), 2);
+        assert!(map_coefficients(&poly, |_| Fq::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_evaluate_symbolic() {
+        // 2·x_0^3 + x_0·x_2 + 5 with x_0 = 1 + t, x_1 = t^2, x_2 = 3t
+        let poly = SparsePolynomial::from_coefficients_vec(
+            3,
+            vec![
+                (Fq::from(2), SparseTerm::new(vec![(0, 3)])),
+                (Fq::from(1), SparseTerm::new(vec![(0, 1), (2, 1)])),
+                (Fq::from(5), SparseTerm::new(vec![])),
+            ],
+        );
+        let substitutions = [
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(1)]),
+            DensePolynomial::from_coefficients_vec(vec![Fq::zero(), Fq::zero(), Fq::from(1)]),
+            DensePolynomial::from_coefficients_vec(vec![Fq::zero(), Fq::from(3)]),
+        ];
+        let composed = evaluate_symbolic(&poly, &substitutions);
+
+        assert_eq!(composed.degree(), 3);
+        for t in [0u64, 1, 7, 100].map(Fq::from) {
+            let point: Vec<Fq> = substitutions.iter().map(|s| s.evaluate(&t)).collect();
+            assert_eq!(composed.evaluate(&t), poly.evaluate(&point));
+        }
+        assert!(evaluate_symbolic(&SparsePolynomial::zero(), &substitutions).is_zero());
+    }
+
+    #[test]
+    fn test_is_constant() {
+        let zero = SparsePolynomial::<Fq, SparseTerm>::zero();
+        assert!(is_constant(&zero));
+        assert_eq!(as_constant(&zero), Some(Fq::zero()));
+
+        // 3 + 4, split over two terms
+        let seven = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![
+                (Fq::from(3), SparseTerm::new(vec![])),
+                (Fq::from(4), SparseTerm::new(vec![])),
+            ],
+        );
+        assert!(is_constant(&seven));
+        assert_eq!(as_constant(&seven), Some(Fq::from(7)));
+
+        // x_0·x_1 + 1
+        let product = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![
+                (Fq::from(1), SparseTerm::new(vec![(0, 1), (1, 1)])),
+                (Fq::from(1), SparseTerm::new(vec![])),
+            ],
+        );
+        assert!(!is_constant(&product));
+        assert_eq!(as_constant(&product), None);
+
+        // x_1 alone is not constant either
+        let x1 = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![(Fq::from(2), SparseTerm::new(vec![(1, 1)]))],
+        );
+        assert_eq!(as_constant(&x1), None);
+    }
+
+    #[test]
+    fn test_try_pow() {
+        // (x_0 + x_1)^3 = x_0^3 + 3x_0^2·x_1 + 3x_0·x_1^2 + x_1^3
+        let sum = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![
+                (Fq::from(1), SparseTerm::new(vec![(0, 1)])),
+                (Fq::from(1), SparseTerm::new(vec![(1, 1)])),
+            ],
+        );
+        let cube = try_pow(&sum, 3, 4).unwrap();
+        let expected = checked_mul(&checked_mul(&sum, &sum).unwrap(), &sum).unwrap();
+        assert_eq!(cube, expected);
+        assert_eq!(cube.terms.len(), 4);
+
+        assert_eq!(
+            as_constant(&try_pow(&sum, 0, 1).unwrap()),
+            Some(Fq::from(1))
+        );
+    }
+
+    #[test]
+    fn test_try_pow_term_limit() {
+        // (x_0 + x_1 + x_2 + x_3)^20 has C(23, 3) = 1771 terms
+        let dense = SparsePolynomial::from_coefficients_vec(
+            4,
+            (0..4)
+                .map(|var| (Fq::from(1), SparseTerm::new(vec![(var, 1)])))
+                .collect(),
+        );
+        assert_eq!(
+            try_pow(&dense, 20, 500),
+            Err(MPolyError::TooManyTerms { limit: 500 })
+        );
+        assert_eq!(try_pow(&dense, 20, 1771).unwrap().terms.len(), 1771);
+    }
+
+    #[test]
+    fn test_try_evaluate_short_point() {
+        let poly = SparsePolynomial::from_coefficients_vec(
+            3,
+            vec![(Fq::from(1), SparseTerm::new(vec![(2, 1)]))],
+        );
+        assert_eq!(
+            try_evaluate(&poly, &[Fq::from(1), Fq::from(2)]),
+            Err(MPolyError::PointTooShort {
+                required: 3,
+                got: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_evaluate_zero_polynomial() {
+        let zero = SparsePolynomial::<Fq, SparseTerm>::zero();
+        assert_eq!(try_evaluate(&zero, &[]).unwrap(), Fq::zero());
+
+        let declared = SparsePolynomial::<Fq, SparseTerm>::from_coefficients_vec(4, vec![]);
+        assert_eq!(try_evaluate(&declared, &[Fq::from(1)]).unwrap(), Fq::zero());
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        // (x_0 + x_1)(x_0 - x_1) = x_0^2 - x_1^2
+        let a = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![
+                (Fq::from(1), SparseTerm::new(vec![(0, 1)])),
+                (Fq::from(1), SparseTerm::new(vec![(1, 1)])),
+            ],
+        );
+        let b = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![
+                (Fq::from(1), SparseTerm::new(vec![(0, 1)])),
+                (-Fq::from(1), SparseTerm::new(vec![(1, 1)])),
+            ],
+        );
+        let expected = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![
+                (Fq::from(1), SparseTerm::new(vec![(0, 2)])),
+                (-Fq::from(1), SparseTerm::new(vec![(1, 2)])),
+            ],
+        );
+
+        assert_eq!(checked_mul(&a, &b).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_checked_mul_exponent_overflow() {
+        let near_max = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![(
+                Fq::from(1),
+                SparseTerm::new(vec![(0, usize::MAX - 1), (1, 1)]),
+            )],
+        );
+        let x_0 = SparsePolynomial::from_coefficients_vec(
+            1,
+            vec![(Fq::from(1), SparseTerm::new(vec![(0, 1)]))],
+        );
+        let x_0_squared = checked_mul(&x_0, &x_0).unwrap();
+
+        let product = checked_mul(&near_max, &x_0).unwrap();
+        assert_eq!(product.terms[0].1[0], (0, usize::MAX));
+        assert_eq!(
+            checked_mul(&near_max, &x_0_squared),
+            Err(MPolyError::ExponentOverflow { var: 0 })
+        );
+    }
+
+    #[test]
+    fn test_permute_variables() {
+        // 3·x_0^2·x_1 + x_2
+        let poly = SparsePolynomial::from_coefficients_vec(
+            3,
+            vec![
+                (Fq::from(3), SparseTerm::new(vec![(0, 2), (1, 1)])),
+                (Fq::from(1), SparseTerm::new(vec![(2, 1)])),
+            ],
+        );
+        let mapping = [2, 0, 1];
+        let inverse = [1, 2, 0];
+
+        let permuted = permute_variables(&poly, &mapping);
+        assert_eq!(permute_variables(&permuted, &inverse), poly);
+
+        let point = [Fq::from(2), Fq::from(5), Fq::from(7)];
+        let mut relabeled = [Fq::from(0); 3];
+        for (i, value) in point.iter().enumerate() {
+            relabeled[mapping[i]] = *value;
+        }
+        assert_eq!(
+            permuted.evaluate(&relabeled.to_vec()),
+            poly.evaluate(&point.to_vec())
+        );
+    }
+
+    #[test]
+    fn test_permute_variables_shift() {
+        let poly = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![(Fq::from(1), SparseTerm::new(vec![(0, 1), (1, 2)]))],
+        );
+        let shifted = permute_variables(&poly, &[3, 4]);
+
+        assert_eq!(shifted.num_vars, 5);
+        assert_eq!(shifted.terms[0].1, SparseTerm::new(vec![(3, 1), (4, 2)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "mapping must cover every variable")]
+    fn test_permute_variables_short_mapping() {
+        let poly = SparsePolynomial::<Fq, SparseTerm>::from_coefficients_vec(3, vec![]);
+        permute_variables(&poly, &[0, 1]);
+    }
+
+    #[test]
+    fn test_homogeneous_components() {
+        // x_0^2·x_1 + 4·x_1^3 + 2·x_0 + 3·x_1 + 7
+        let poly = SparsePolynomial::from_coefficients_vec(
+            2,
+            vec![
+                (Fq::from(1), SparseTerm::new(vec![(0, 2), (1, 1)])),
+                (Fq::from(4), SparseTerm::new(vec![(1, 3)])),
+                (Fq::from(2), SparseTerm::new(vec![(0, 1)])),
+                (Fq::from(3), SparseTerm::new(vec![(1, 1)])),
+                (Fq::from(7), SparseTerm::new(vec![])),
+            ],
+        );
+        let components = homogeneous_components(&poly);
+
+        assert_eq!(
+            components.keys().copied().collect::<Vec<_>>(),
+            vec![0, 1, 3]
+        );
+        assert_eq!(components[&3].terms.len(), 2);
+        for (degree, component) in &components {
+            assert!(component.terms.iter().all(|(_, t)| t.degree() == *degree));
+        }
+
+        let sum = components
+            .values()
+            .fold(SparsePolynomial::zero(), |acc, c| &acc + c);
+        assert_eq!(sum, poly);
+    }
+
+    #[test]
+    fn test_embed() {
+        let univariate =
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(5), Fq::from(0), Fq::from(3)]);
+        let embedded = embed(&univariate, 1, 3);
+
+        let point = vec![Fq::from(100), Fq::from(7), Fq::from(200)];
+        assert_eq!(embedded.evaluate(&point), univariate.evaluate(&Fq::from(7)));
+        assert_eq!(embedded.terms.len(), 2);
+
+        // padding only changes the declared variable count
+        let padded = embed(&univariate, 1, 6);
+        assert_eq!(padded.num_vars, 6);
+        assert_eq!(padded.terms, embedded.terms);
+        assert!(embed(&DensePolynomial::<Fq>::zero(), 0, 1).is_zero());
+    }
+}
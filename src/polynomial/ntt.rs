@@ -0,0 +1,138 @@
+use ark_ff::FftField;
+use ark_std::{iter, vec::Vec};
+
+// Radix-2 NTT over a fixed power-of-two size, with the twiddles computed once so
+// repeated transforms of the same size only pay for the butterflies.
+#[derive(Clone, Debug)]
+pub struct NttDomain<F: FftField> {
+    size: usize,
+    twiddles: Vec<F>,
+    inv_twiddles: Vec<F>,
+    size_inv: F,
+}
+
+impl<F: FftField> NttDomain<F> {
+    pub fn new(size: usize) -> Self {
+        assert!(size.is_power_of_two(), "NTT size must be a power of two");
+        let omega = F::get_root_of_unity(size as u64).expect("field has no subgroup of this order");
+        let omega_inv = omega.inverse().expect("roots of unity are nonzero");
+
+        Self {
+            size,
+            twiddles: powers(omega, size / 2),
+            inv_twiddles: powers(omega_inv, size / 2),
+            size_inv: F::from(size as u64).inverse().expect("size is nonzero"),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    // Coefficients in, evaluations over <ω> in natural order out.
+    pub fn forward(&self, data: &mut [F]) {
+        assert_eq!(
+            data.len(),
+            self.size,
+            "input length must match the NTT size"
+        );
+        transform(data, &self.twiddles);
+    }
+
+    pub fn inverse(&self, data: &mut [F]) {
+        assert_eq!(
+            data.len(),
+            self.size,
+            "input length must match the NTT size"
+        );
+        transform(data, &self.inv_twiddles);
+        for x in data.iter_mut() {
+            *x *= self.size_inv;
+        }
+    }
+}
+
+fn powers<F: FftField>(base: F, count: usize) -> Vec<F> {
+    iter::successors(Some(F::one()), |x| Some(*x * base))
+        .take(count)
+        .collect()
+}
+
+// Iterative Cooley-Tukey: bit-reverse the input, then merge blocks of doubling
+// size. A block of size 2·half uses every (n / 2·half)-th twiddle.
+fn transform<F: FftField>(data: &mut [F], twiddles: &[F]) {
+    let n = data.len();
+    bit_reverse_permute(data);
+
+    let mut half = 1;
+    while half < n {
+        let stride = n / (2 * half);
+        for block in data.chunks_mut(2 * half) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (j, (a, b)) in lo.iter_mut().zip(hi).enumerate() {
+                let t = *b * twiddles[j * stride];
+                *b = *a - t;
+                *a += t;
+            }
+        }
+        half *= 2;
+    }
+}
+
+fn bit_reverse_permute<F>(data: &mut [F]) {
+    let n = data.len();
+    if n <= 2 {
+        return;
+    }
+
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_poly::{DenseUVPolynomial, univariate::DensePolynomial};
+
+    use super::*;
+    use crate::field::Fq;
+    use crate::polynomial::{domain, fast_evaluate_domain};
+
+    #[test]
+    fn test_ntt_matches_fft() {
+        for size in [1, 2, 8, 64] {
+            let ntt = NttDomain::<Fq>::new(size);
+            let coeffs: Vec<Fq> = (0..size as u64).map(|i| Fq::from(i * i + 7)).collect();
+            let poly = DensePolynomial::from_coefficients_slice(&coeffs);
+
+            let mut evals = coeffs.clone();
+            ntt.forward(&mut evals);
+            assert_eq!(evals, fast_evaluate_domain(&poly, &domain(size)));
+
+            ntt.inverse(&mut evals);
+            assert_eq!(evals, coeffs);
+        }
+    }
+
+    #[test]
+    fn test_ntt_domain_reuse() {
+        let ntt = NttDomain::<Fq>::new(16);
+        for seed in 1..4u64 {
+            let coeffs: Vec<Fq> = (0..16).map(|i| Fq::from(seed * 1000 + i)).collect();
+            let mut data = coeffs.clone();
+            ntt.forward(&mut data);
+            ntt.inverse(&mut data);
+            assert_eq!(data, coeffs);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "input length must match the NTT size")]
+    fn test_ntt_wrong_length() {
+        NttDomain::<Fq>::new(8).forward(&mut [Fq::from(1); 4]);
+    }
+}
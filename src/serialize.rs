@@ -0,0 +1,65 @@
+use ark_serialize::{CanonicalDeserialize, Compress, Read, SerializationError, Validate};
+use ark_std::vec::Vec;
+
+// Most items reserved ahead of reading them.
+const MAX_PREALLOC: u64 = 1 << 12;
+
+// Reads a u64 length prefix and that many items. ark-serialize's Vec decoding
+// reserves the full prefix up front, so a forged length panics or aborts on
+// allocation before the missing bytes are noticed; here the vector grows as
+// items actually arrive, and short input fails at the first missing item.
+pub(crate) fn read_vec_with<R: Read, T>(
+    reader: &mut R,
+    compress: Compress,
+    validate: Validate,
+    mut read_item: impl FnMut(&mut R) -> Result<T, SerializationError>,
+) -> Result<Vec<T>, SerializationError> {
+    let len = u64::deserialize_with_mode(&mut *reader, compress, validate)?;
+    let mut items = Vec::with_capacity(len.min(MAX_PREALLOC) as usize);
+    for _ in 0..len {
+        items.push(read_item(reader)?);
+    }
+    Ok(items)
+}
+
+pub(crate) fn read_vec<R: Read, T: CanonicalDeserialize>(
+    reader: &mut R,
+    compress: Compress,
+    validate: Validate,
+) -> Result<Vec<T>, SerializationError> {
+    read_vec_with(reader, compress, validate, |r| {
+        T::deserialize_with_mode(r, compress, validate)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_serialize::CanonicalSerialize;
+
+    use super::*;
+    use crate::field::Fq;
+
+    #[test]
+    fn test_read_vec_matches_ark() {
+        let values: Vec<Vec<Fq>> = vec![vec![Fq::from(1), Fq::from(2)], vec![], vec![Fq::from(3)]];
+        let mut bytes = Vec::new();
+        values.serialize_compressed(&mut bytes).unwrap();
+
+        let mut reader = &bytes[..];
+        let decoded = read_vec_with(&mut reader, Compress::Yes, Validate::Yes, |r| {
+            read_vec::<_, Fq>(r, Compress::Yes, Validate::Yes)
+        })
+        .unwrap();
+        assert_eq!(decoded, values);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_read_vec_huge_length_prefix() {
+        for len in [u64::MAX, u64::MAX / 2, u64::MAX / 16] {
+            let mut bytes = len.to_le_bytes().to_vec();
+            bytes.extend([0; 16]);
+            assert!(read_vec::<_, Fq>(&mut &bytes[..], Compress::Yes, Validate::Yes).is_err());
+        }
+    }
+}
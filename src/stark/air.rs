@@ -1,17 +1,296 @@
-use ark_ff::PrimeField;
-use ark_poly::multivariate::{SparsePolynomial, SparseTerm};
+use ark_ff::{PrimeField, Zero};
+use ark_poly::{
+    DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain, Polynomial,
+    multivariate::{SparsePolynomial, SparseTerm, Term},
+    univariate::DensePolynomial,
+};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+};
+use ark_std::{vec, vec::Vec};
 
-#[derive(Clone, Debug)]
+use crate::{
+    error::PolyError,
+    polynomial::{algebra::exact_div, divide_by_linear, multivariate::evaluate_symbolic},
+    serialize::{read_vec, read_vec_with},
+};
+
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct BoundaryConstraint<F: PrimeField> {
     pub cycle: usize,
     pub register: usize,
     pub value: F,
 }
 
-#[derive(Clone)]
+#[derive(Clone, CanonicalSerialize)]
 pub struct Air<F: PrimeField> {
     pub num_registers: usize,
     pub original_trace_length: usize,
     pub transition_constraints: Vec<SparsePolynomial<F, SparseTerm>>,
     pub boundary_constraints: Vec<BoundaryConstraint<F>>,
 }
+
+impl<F: PrimeField> Valid for Air<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.transition_constraints.check()?;
+        self.boundary_constraints.check()
+    }
+}
+
+// Public inputs are untrusted too, so every Vec down to the terms of each
+// constraint goes through read_vec.
+impl<F: PrimeField> CanonicalDeserialize for Air<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let read_constraint = |r: &mut R| {
+            Ok(SparsePolynomial {
+                num_vars: usize::deserialize_with_mode(&mut *r, compress, validate)?,
+                terms: read_vec_with(r, compress, validate, |r| {
+                    let coeff = F::deserialize_with_mode(&mut *r, compress, validate)?;
+                    let term = SparseTerm::new(read_vec(r, compress, validate)?);
+                    Ok((coeff, term))
+                })?,
+            })
+        };
+
+        Ok(Self {
+            num_registers: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            original_trace_length: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            transition_constraints: read_vec_with(
+                &mut reader,
+                compress,
+                validate,
+                read_constraint,
+            )?,
+            boundary_constraints: read_vec(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+// (column_poly - expected_value) / (x - row_point). The division is exact exactly
+// when the column takes the expected value at the row, so a violated boundary
+// constraint is reported instead of leaving a remainder behind.
+pub fn boundary_quotient<F: PrimeField>(
+    column_poly: &DensePolynomial<F>,
+    row_point: F,
+    expected_value: F,
+) -> Result<DensePolynomial<F>, PolyError> {
+    // dividing out (x - row_point) leaves column_poly(row_point) as the remainder,
+    // and subtracting the constant only shifts that remainder
+    let (quotient, value) = divide_by_linear(column_poly, row_point);
+    match value == expected_value {
+        true => Ok(quotient),
+        false => Err(PolyError::InexactDivision),
+    }
+}
+
+// (x^T - 1) / (x - ω^(T-1)): vanishes on every row that has a successor.
+pub fn transition_zerofier<F: PrimeField>(
+    domain: &GeneralEvaluationDomain<F>,
+) -> DensePolynomial<F> {
+    let vanishing: DensePolynomial<F> = domain.vanishing_polynomial().into();
+    let last_point = domain.element(domain.size() - 1);
+    let (zerofier, _) = divide_by_linear(&vanishing, last_point);
+    zerofier
+}
+
+// Σ alphas_k · C_k(trace(x), next_trace(x)) / Z(x), where the constraints use
+// x_0..x_{w-1} for the current row and x_w..x_{2w-1} for the next one. Errors if
+// some constraint does not hold on the whole transition domain.
+pub fn compose_transition<F: PrimeField>(
+    constraints: &[SparsePolynomial<F, SparseTerm>],
+    trace_polys: &[DensePolynomial<F>],
+    next_trace_polys: &[DensePolynomial<F>],
+    alphas: &[F],
+    transition_domain: &GeneralEvaluationDomain<F>,
+) -> Result<DensePolynomial<F>, PolyError> {
+    assert_eq!(constraints.len(), alphas.len(), "one weight per constraint");
+
+    let zerofier = transition_zerofier(transition_domain);
+    let substitutions: Vec<DensePolynomial<F>> = trace_polys
+        .iter()
+        .chain(next_trace_polys)
+        .cloned()
+        .collect();
+
+    let mut composition = DensePolynomial::from_coefficients_vec(vec![]);
+    for (constraint, alpha) in constraints.iter().zip(alphas) {
+        let quotient = exact_div(&evaluate_symbolic(constraint, &substitutions), &zerofier)?;
+        composition += (*alpha, &quotient);
+    }
+    Ok(composition)
+}
+
+// p(x)·(1 + randomizer·x^k) with k = target_degree - deg p, so every composition
+// term can be brought to a common degree. The original value is recovered as
+// adjusted(z) / (1 + randomizer·z^k). The zero polynomial is returned as is.
+pub fn adjust_degree<F: PrimeField>(
+    poly: &DensePolynomial<F>,
+    target_degree: usize,
+    randomizer: F,
+) -> DensePolynomial<F> {
+    if poly.is_zero() {
+        return poly.clone();
+    }
+    let k = target_degree
+        .checked_sub(poly.degree())
+        .expect("target degree is below the degree of the polynomial");
+
+    let mut factor = vec![F::zero(); k + 1];
+    factor[0] = F::one();
+    factor[k] += randomizer;
+    let adjusted = poly * &DensePolynomial::from_coefficients_vec(factor);
+    assert!(
+        !adjusted.is_zero() && adjusted.degree() == target_degree,
+        "randomizer does not raise the degree to the target"
+    );
+    adjusted
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::Field;
+    use ark_poly::{DenseMVPolynomial, multivariate::Term};
+
+    use super::*;
+    use crate::{
+        field::Fq,
+        polynomial::{domain, fast_interpolate, shift_poly},
+    };
+
+    // registers (a, b); next row (b, a + b)
+    fn fibonacci_constraints() -> Vec<SparsePolynomial<Fq, SparseTerm>> {
+        let one = Fq::from(1);
+        vec![
+            SparsePolynomial::from_coefficients_vec(
+                4,
+                vec![
+                    (one, SparseTerm::new(vec![(2, 1)])),
+                    (-one, SparseTerm::new(vec![(1, 1)])),
+                ],
+            ),
+            SparsePolynomial::from_coefficients_vec(
+                4,
+                vec![
+                    (one, SparseTerm::new(vec![(3, 1)])),
+                    (-one, SparseTerm::new(vec![(0, 1)])),
+                    (-one, SparseTerm::new(vec![(1, 1)])),
+                ],
+            ),
+        ]
+    }
+
+    fn fibonacci_polys(
+        domain: &GeneralEvaluationDomain<Fq>,
+        tamper: bool,
+    ) -> (Vec<DensePolynomial<Fq>>, Vec<DensePolynomial<Fq>>) {
+        let mut rows = vec![(Fq::from(1), Fq::from(1))];
+        for _ in 1..domain.size() {
+            let (a, b) = *rows.last().unwrap();
+            rows.push((b, a + b));
+        }
+        if tamper {
+            rows[4].1 += Fq::from(1);
+        }
+
+        let columns = [
+            rows.iter().map(|r| r.0).collect::<Vec<_>>(),
+            rows.iter().map(|r| r.1).collect::<Vec<_>>(),
+        ];
+        let polys: Vec<_> = columns
+            .into_iter()
+            .map(|c| fast_interpolate(c, domain))
+            .collect();
+        let next = polys
+            .iter()
+            .map(|p| shift_poly(p, domain.group_gen()))
+            .collect();
+        (polys, next)
+    }
+
+    #[test]
+    fn test_compose_transition_fibonacci() {
+        let domain = domain::<Fq>(8);
+        let (polys, next) = fibonacci_polys(&domain, false);
+        let constraints = fibonacci_constraints();
+        let alphas = [Fq::from(3), Fq::from(10)];
+
+        let composition =
+            compose_transition(&constraints, &polys, &next, &alphas, &domain).unwrap();
+
+        let zerofier = transition_zerofier(&domain);
+        assert_eq!(zerofier.degree(), 7);
+        let mut numerator = DensePolynomial::from_coefficients_vec(vec![]);
+        let substitutions: Vec<_> = polys.iter().chain(&next).cloned().collect();
+        for (constraint, alpha) in constraints.iter().zip(alphas) {
+            numerator += (alpha, &evaluate_symbolic(constraint, &substitutions));
+        }
+        assert_eq!(&composition * &zerofier, numerator);
+    }
+
+    #[test]
+    fn test_compose_transition_violated() {
+        let domain = domain::<Fq>(8);
+        let (polys, next) = fibonacci_polys(&domain, true);
+        let alphas = [Fq::from(3), Fq::from(10)];
+
+        assert_eq!(
+            compose_transition(&fibonacci_constraints(), &polys, &next, &alphas, &domain),
+            Err(PolyError::InexactDivision)
+        );
+    }
+
+    #[test]
+    fn test_boundary_quotient_satisfied() {
+        let domain = domain::<Fq>(8);
+        let column: Vec<Fq> = (0..8u64).map(|i| Fq::from(i * i + 2)).collect();
+        let poly = fast_interpolate(column.clone(), &domain);
+        let row_point = domain.element(3);
+
+        let quotient = boundary_quotient(&poly, row_point, column[3]).unwrap();
+        let linear = DensePolynomial::from_coefficients_vec(vec![-row_point, Fq::from(1)]);
+        let constant = DensePolynomial::from_coefficients_vec(vec![column[3]]);
+        assert_eq!(&(&quotient * &linear) + &constant, poly);
+        assert_eq!(quotient.degree(), poly.degree() - 1);
+    }
+
+    #[test]
+    fn test_boundary_quotient_violated() {
+        let domain = domain::<Fq>(8);
+        let column: Vec<Fq> = (0..8u64).map(|i| Fq::from(i * i + 2)).collect();
+        let poly = fast_interpolate(column.clone(), &domain);
+
+        assert_eq!(
+            boundary_quotient(&poly, domain.element(3), column[3] + Fq::from(1)),
+            Err(PolyError::InexactDivision)
+        );
+    }
+
+    #[test]
+    fn test_adjust_degree() {
+        let poly = DensePolynomial::from_coefficients_vec((1..=4u64).map(Fq::from).collect());
+        let r = Fq::from(11);
+
+        for target in [3, 4, 10] {
+            let adjusted = adjust_degree(&poly, target, r);
+            assert_eq!(adjusted.degree(), target);
+
+            let k = (target - poly.degree()) as u64;
+            for z in [2u64, 5, 1000].map(Fq::from) {
+                let factor = Fq::from(1) + r * z.pow([k]);
+                assert_eq!(adjusted.evaluate(&z) / factor, poly.evaluate(&z));
+            }
+        }
+        assert!(adjust_degree(&DensePolynomial::<Fq>::zero(), 7, r).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "target degree is below")]
+    fn test_adjust_degree_below_current() {
+        let poly = DensePolynomial::from_coefficients_vec((1..=4u64).map(Fq::from).collect());
+        adjust_degree(&poly, 2, Fq::from(11));
+    }
+}
@@ -0,0 +1,75 @@
+use ark_ff::{PrimeField, Zero};
+use ark_poly::{DenseUVPolynomial, univariate::DensePolynomial};
+use ark_std::vec;
+
+use crate::polynomial::divide_by_linear;
+
+// Σ α_i · (f_i(x) - f_i(z)) / (x - z), where evals[i] must equal f_i(z).
+pub fn composition_polynomial<F: PrimeField>(
+    polys: &[DensePolynomial<F>],
+    z: F,
+    evals: &[F],
+    alphas: &[F],
+) -> DensePolynomial<F> {
+    assert_eq!(polys.len(), evals.len(), "one evaluation per polynomial");
+    assert_eq!(polys.len(), alphas.len(), "one coefficient per polynomial");
+
+    let mut composition = DensePolynomial::zero();
+    for ((poly, eval), alpha) in polys.iter().zip(evals).zip(alphas) {
+        let numerator = poly - &DensePolynomial::from_coefficients_vec(vec![*eval]);
+        let (quotient, remainder) = divide_by_linear(&numerator, z);
+        assert!(
+            remainder.is_zero(),
+            "claimed evaluation does not match the polynomial"
+        );
+        composition += (*alpha, &quotient);
+    }
+
+    composition
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_poly::Polynomial;
+
+    use super::*;
+    use crate::field::Fq;
+
+    fn polys() -> Vec<DensePolynomial<Fq>> {
+        vec![
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(1), Fq::from(2), Fq::from(3)]),
+            DensePolynomial::from_coefficients_vec(vec![Fq::from(4), Fq::from(5), Fq::from(6)]),
+        ]
+    }
+
+    #[test]
+    fn test_deep_composition() {
+        let polys = polys();
+        let z = Fq::from(1234);
+        let evals: Vec<Fq> = polys.iter().map(|p| p.evaluate(&z)).collect();
+        let alphas = vec![Fq::from(7), Fq::from(11)];
+
+        let deep = composition_polynomial(&polys, z, &evals, &alphas);
+        assert_eq!(deep.degree(), 1);
+
+        let x = Fq::from(99);
+        let expected: Fq = polys
+            .iter()
+            .zip(&evals)
+            .zip(&alphas)
+            .map(|((p, e), a)| *a * (p.evaluate(&x) - e) / (x - z))
+            .sum();
+        assert_eq!(deep.evaluate(&x), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "claimed evaluation does not match")]
+    fn test_deep_composition_wrong_evaluation() {
+        let polys = polys();
+        let z = Fq::from(1234);
+        let mut evals: Vec<Fq> = polys.iter().map(|p| p.evaluate(&z)).collect();
+        evals[1] += Fq::from(1);
+
+        composition_polynomial(&polys, z, &evals, &[Fq::from(1), Fq::from(1)]);
+    }
+}
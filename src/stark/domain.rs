@@ -1,31 +1,74 @@
 use ark_ff::PrimeField;
-use ark_poly::{EvaluationDomain, GeneralEvaluationDomain, Polynomial};
+use ark_poly::{EvaluationDomain, GeneralEvaluationDomain};
+use ark_std::vec::Vec;
 
-use crate::polynomial::domain;
+use crate::polynomial::{domain, vanishing_evals_on_coset};
 
 pub struct PreprocessedDomain<F: PrimeField> {
     pub trace_domain: GeneralEvaluationDomain<F>,
     pub eval_domain: GeneralEvaluationDomain<F>,
+    // the LDE is taken over coset_shift·<eval_domain>, off the trace subgroup
+    pub coset_shift: F,
     pub vanishing_evals: Vec<F>,
 }
 
 impl<F: PrimeField> PreprocessedDomain<F> {
     pub fn new(trace_length: usize, blowup_factor: usize) -> Self {
+        // the trace subgroup is the smallest power of two holding every row
         let trace_domain = domain(trace_length);
-        let eval_length = trace_length * blowup_factor;
+        let eval_length = trace_domain.size() * blowup_factor;
         let eval_domain = domain(eval_length);
 
         // Precompute vanishing poly evaluations on eval domain
-        let vanishing = trace_domain.vanishing_polynomial();
-        let vanishing_evals = eval_domain
-            .elements()
-            .map(|x| vanishing.evaluate(&x))
-            .collect();
+        let vanishing_evals =
+            vanishing_evals_on_coset(trace_domain.size(), F::one(), eval_domain.size());
 
         Self {
             trace_domain,
             eval_domain,
+            coset_shift: F::GENERATOR,
             vanishing_evals,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{FftField, Field};
+
+    use super::*;
+    use crate::field::Fq;
+
+    fn has_order(g: Fq, n: u64) -> bool {
+        g.pow([n]) == Fq::from(1) && g.pow([n / 2]) != Fq::from(1)
+    }
+
+    #[test]
+    fn test_domain_sizes_and_generators() {
+        for (trace_length, blowup, trace_size, eval_size) in
+            [(8, 4, 8, 32), (5, 4, 8, 32), (1000, 2, 1024, 2048)]
+        {
+            let d = PreprocessedDomain::<Fq>::new(trace_length, blowup);
+            assert_eq!(d.trace_domain.size(), trace_size);
+            assert_eq!(d.eval_domain.size(), eval_size);
+            assert!(has_order(d.trace_domain.group_gen(), trace_size as u64));
+            assert!(has_order(d.eval_domain.group_gen(), eval_size as u64));
+            // the trace subgroup sits inside the evaluation subgroup
+            assert_eq!(
+                d.eval_domain.group_gen().pow([blowup as u64]),
+                d.trace_domain.group_gen()
+            );
+        }
+    }
+
+    #[test]
+    fn test_coset_shift_leaves_the_subgroup() {
+        let d = PreprocessedDomain::<Fq>::new(16, 4);
+        assert_eq!(d.coset_shift, Fq::GENERATOR);
+        // shift^|E| = 1 exactly when the shift lies in the evaluation subgroup
+        assert_ne!(
+            d.coset_shift.pow([d.eval_domain.size() as u64]),
+            Fq::from(1)
+        );
+    }
+}
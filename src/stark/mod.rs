@@ -1,7 +1,9 @@
 pub mod air;
+pub mod deep;
 pub mod domain;
 pub mod prover;
 mod quotient;
+pub mod trace;
 pub mod verifier;
 
 #[cfg(test)]
@@ -9,11 +11,12 @@ mod tests {
     use ark_ff::{PrimeField, Zero};
     use ark_poly::DenseMVPolynomial;
     use ark_poly::multivariate::{SparsePolynomial, SparseTerm, Term};
+    use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 
     use crate::crypto::transcript::Transcript;
     use crate::field::Fq;
     use crate::stark::air::{Air, BoundaryConstraint};
-    use crate::stark::prover::{prove, prove_fast};
+    use crate::stark::prover::{StarkProof, prove, prove_fast};
     use crate::stark::verifier::verify;
 
     fn repeated_squaring_air<F: PrimeField>(trace_length: usize, input: F, output: F) -> Air<F> {
@@ -43,6 +46,23 @@ mod tests {
             ],
         }
     }
+    // A prove_fast proof of `trace_length` repeated squarings of 3, with its AIR.
+    fn squaring_proof(trace_length: usize) -> (StarkProof<Fq>, Air<Fq>) {
+        let input = Fq::from(3);
+
+        let mut trace = vec![vec![input]];
+        for i in 1..trace_length {
+            let prev = trace[i - 1][0];
+            trace.push(vec![prev * prev]);
+        }
+
+        let output = trace[trace_length - 1][0];
+        let air = repeated_squaring_air(trace_length, input, output);
+
+        let mut prover_transcript = Transcript::new(Fq::zero());
+        (prove_fast(trace, &air, 4, &mut prover_transcript), air)
+    }
+
     #[test]
     fn test_stark_repeated_squaring() {
         let input = Fq::from(3);
@@ -85,6 +105,154 @@ mod tests {
         assert!(verify(&proof, &air, &mut verifier_transcript).is_ok());
     }
 
+    #[test]
+    fn test_stark_proof_bytes_roundtrip() {
+        let (proof, air) = squaring_proof(8);
+
+        let bytes = proof.to_bytes();
+        let decoded = StarkProof::<Fq>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.to_bytes(), bytes);
+
+        let mut verifier_transcript = Transcript::new(Fq::zero());
+        assert!(verify(&decoded, &air, &mut verifier_transcript).is_ok());
+
+        assert!(StarkProof::<Fq>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_stark_proof_huge_length_prefix() {
+        let bytes = squaring_proof(8).0.to_bytes();
+
+        // every field is an 8-byte word, so this hits each length prefix in turn
+        for len in [u64::MAX, u64::MAX / 2, u64::MAX / 16] {
+            for word in 0..bytes.len() / 8 {
+                let mut forged = bytes.clone();
+                forged[8 * word..8 * word + 8].copy_from_slice(&len.to_le_bytes());
+                let _ = StarkProof::<Fq>::from_bytes(&forged);
+                let _ = StarkProof::<Fq>::read_from(&forged[..]);
+            }
+        }
+
+        let mut forged = bytes[..32].to_vec();
+        forged.extend((u64::MAX / 2).to_le_bytes());
+        assert!(StarkProof::<Fq>::from_bytes(&forged).is_err());
+    }
+
+    #[test]
+    fn test_stark_proof_read_from_stream() {
+        let (proof, _) = squaring_proof(8);
+
+        // a trailing second proof must be left unread in the stream
+        let mut bytes = proof.to_bytes();
+        let len = bytes.len();
+        bytes.extend(proof.to_bytes());
+        let mut cursor = std::io::Cursor::new(bytes.clone());
+
+        let streamed = StarkProof::<Fq>::read_from(&mut cursor).unwrap();
+        assert_eq!(cursor.position() as usize, len);
+        assert_eq!(
+            streamed.to_bytes(),
+            StarkProof::<Fq>::from_bytes(&bytes[..len])
+                .unwrap()
+                .to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_stark_proof_text_encodings() {
+        let (proof, air) = squaring_proof(8);
+        let bytes = proof.to_bytes();
+
+        let from_hex = StarkProof::<Fq>::from_hex(&proof.to_hex()).unwrap();
+        assert_eq!(from_hex.to_bytes(), bytes);
+
+        let from_base64 = StarkProof::<Fq>::from_base64(&proof.to_base64()).unwrap();
+        assert_eq!(from_base64.to_bytes(), bytes);
+
+        let mut verifier_transcript = Transcript::new(Fq::zero());
+        assert!(verify(&from_base64, &air, &mut verifier_transcript).is_ok());
+    }
+
+    #[test]
+    fn test_stark_proof_text_encodings_malformed() {
+        let err = StarkProof::<Fq>::from_hex("0g").err().unwrap();
+        assert!(err.to_string().starts_with("malformed hex"));
+        assert!(StarkProof::<Fq>::from_hex("abc").is_err());
+
+        let err = StarkProof::<Fq>::from_base64("not base64!").err().unwrap();
+        assert!(err.to_string().starts_with("malformed base64"));
+
+        // well-formed encodings of a truncated proof
+        let err = StarkProof::<Fq>::from_hex("00").err().unwrap();
+        assert!(err.to_string().starts_with("malformed proof"));
+        assert!(StarkProof::<Fq>::from_base64("AAAA").is_err());
+
+        // short encodings whose layers_root length prefix claims far more data
+        for len in [u64::MAX / 2, u64::MAX / 16] {
+            let mut forged = Vec::new();
+            for word in [8u64, 7, 1, 2, len] {
+                forged.extend(word.to_le_bytes());
+            }
+            let err = StarkProof::<Fq>::from_hex(&hex::encode(&forged))
+                .err()
+                .unwrap();
+            assert!(err.to_string().starts_with("malformed proof"));
+
+            let err = StarkProof::<Fq>::from_base64(&BASE64.encode(&forged))
+                .err()
+                .unwrap();
+            assert!(err.to_string().starts_with("malformed proof"));
+        }
+    }
+
+    #[test]
+    fn test_stark_proof_size_report() {
+        let (proof, _) = squaring_proof(8);
+
+        let report = proof.size_report();
+        let total: usize = report.iter().map(|(_, size)| size).sum();
+        assert_eq!(total, proof.serialized_size());
+        assert_eq!(proof.serialized_size(), proof.to_bytes().len());
+
+        // the query openings dominate
+        let (largest, _) = report.iter().max_by_key(|(_, size)| *size).unwrap();
+        assert_eq!(*largest, "fri.decommitments");
+    }
+
+    #[test]
+    fn test_stark_proof_bytes_are_fixed_width() {
+        let (proof, _) = squaring_proof(8);
+
+        // every usize, length prefix and Goldilocks element is one 8-byte word
+        let fri = &proof.fri_proof;
+        let opening_words = |path_len: usize| 3 + 1 + path_len;
+        let decommitment_words: usize = fri
+            .decommitment_list
+            .iter()
+            .map(|d| {
+                let evaluations: usize = d.evaluations.iter().map(|e| 1 + e.len()).sum();
+                let auth_paths: usize = d
+                    .auth_paths
+                    .iter()
+                    .map(|paths| {
+                        1 + paths
+                            .iter()
+                            .map(|p| opening_words(p.auth_path.len()))
+                            .sum::<usize>()
+                    })
+                    .sum();
+                1 + evaluations + 1 + auth_paths
+            })
+            .sum();
+        let fri_words = 4 + (1 + fri.layers_root.len()) + 1 + (1 + decommitment_words);
+        let trace_words = (1 + proof.trace_roots.len())
+            + (1 + proof.trace_evals_at_z.len())
+            + (1 + proof.trace_evals_at_omega_z.len())
+            + 1;
+
+        assert_eq!(proof.to_bytes().len(), 8 * (fri_words + trace_words));
+    }
+
     #[test]
     fn bench_naive_vs_fast() {
         use std::time::Instant;
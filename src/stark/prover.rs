@@ -1,23 +1,27 @@
-use std::vec;
-
 use ark_ff::{PrimeField, Zero};
 use ark_poly::{
     DenseUVPolynomial, EvaluationDomain, Evaluations, Polynomial, univariate::DensePolynomial,
 };
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+};
+use ark_std::{string::String, vec, vec::Vec};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 
 use crate::{
-    crypto::{merkle::MerkleTree, transcript::Transcript},
+    crypto::transcript::Transcript,
     fri::{
         layer::FriLayer,
         prover::{FriProof, generate_proof},
     },
-    polynomial::{domain, shift_poly},
-    stark::{air::Air, domain::PreprocessedDomain},
+    polynomial::{domain, shift_poly, vanishing_evals_on_coset},
+    serialize::read_vec,
+    stark::{air::Air, domain::PreprocessedDomain, trace::CommittedTrace},
 };
 
 use super::quotient::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, CanonicalSerialize)]
 pub struct StarkProof<F: PrimeField> {
     pub fri_proof: FriProof<F>,
     pub trace_roots: Vec<F>,
@@ -26,6 +30,106 @@ pub struct StarkProof<F: PrimeField> {
     pub composition_eval_at_z: F,
 }
 
+impl<F: PrimeField> Valid for StarkProof<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.fri_proof.check()?;
+        self.trace_roots.check()?;
+        self.trace_evals_at_z.check()?;
+        self.trace_evals_at_omega_z.check()?;
+        self.composition_eval_at_z.check()
+    }
+}
+
+// Vectors are decoded with read_vec.
+impl<F: PrimeField> CanonicalDeserialize for StarkProof<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        Ok(Self {
+            fri_proof: FriProof::deserialize_with_mode(&mut reader, compress, validate)?,
+            trace_roots: read_vec(&mut reader, compress, validate)?,
+            trace_evals_at_z: read_vec(&mut reader, compress, validate)?,
+            trace_evals_at_omega_z: read_vec(&mut reader, compress, validate)?,
+            composition_eval_at_z: F::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+impl<F: PrimeField> StarkProof<F> {
+    // Field elements are written as bare fixed-width little-endian words;
+    // the field itself is fixed by `F` at deserialization.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.compressed_size());
+        self.serialize_compressed(&mut bytes)
+            .expect("serializing into a Vec cannot fail");
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        Self::read_from(bytes)
+    }
+
+    // Reads the proof incrementally, e.g. straight off a socket.
+    pub fn read_from<R: Read>(reader: R) -> anyhow::Result<Self> {
+        Self::deserialize_compressed(reader).map_err(|e| anyhow::anyhow!("malformed proof: {e}"))
+    }
+
+    pub fn serialized_size(&self) -> usize {
+        self.compressed_size()
+    }
+
+    // Bytes taken by each part of the proof, in serialization order; the sizes
+    // add up to serialized_size().
+    pub fn size_report(&self) -> Vec<(&'static str, usize)> {
+        let fri = &self.fri_proof;
+        vec![
+            (
+                "fri.params",
+                fri.domain_size.compressed_size()
+                    + fri.coset.compressed_size()
+                    + fri.number_of_queries.compressed_size()
+                    + fri.folding_factor.compressed_size(),
+            ),
+            ("fri.layers_root", fri.layers_root.compressed_size()),
+            ("fri.const_val", fri.const_val.compressed_size()),
+            ("fri.decommitments", fri.decommitment_list.compressed_size()),
+            ("trace_roots", self.trace_roots.compressed_size()),
+            ("trace_evals_at_z", self.trace_evals_at_z.compressed_size()),
+            (
+                "trace_evals_at_omega_z",
+                self.trace_evals_at_omega_z.compressed_size(),
+            ),
+            (
+                "composition_eval_at_z",
+                self.composition_eval_at_z.compressed_size(),
+            ),
+        ]
+    }
+
+    // Text encodings of `to_bytes`, for transport in JSON and similar formats.
+    pub fn to_hex(&self) -> String {
+        hex::encode(self.to_bytes())
+    }
+
+    pub fn from_hex(encoded: &str) -> anyhow::Result<Self> {
+        let bytes = hex::decode(encoded).map_err(|e| anyhow::anyhow!("malformed hex: {e}"))?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn to_base64(&self) -> String {
+        BASE64.encode(self.to_bytes())
+    }
+
+    pub fn from_base64(encoded: &str) -> anyhow::Result<Self> {
+        let bytes = BASE64
+            .decode(encoded)
+            .map_err(|e| anyhow::anyhow!("malformed base64: {e}"))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
 pub fn prove_fast<F: PrimeField>(
     trace: Vec<Vec<F>>,
     air: &Air<F>,
@@ -42,15 +146,14 @@ pub fn prove_fast<F: PrimeField>(
     let w = air.num_registers;
     let omega = trace_domain.group_gen();
 
-    let coset = F::GENERATOR;
-    let trace_polys = interpolate_trace(&trace);
-
-    let mut trace_evals = Vec::with_capacity(w);
-    for trace_poly in &trace_polys {
-        let shifted = shift_poly(trace_poly, coset);
-        let evals = eval_domain.fft(&shifted.coeffs);
-        trace_evals.push(evals);
-    }
+    let coset = preprocessed.coset_shift;
+    let columns: Vec<Vec<F>> = (0..w)
+        .map(|j| trace.iter().map(|row| row[j]).collect())
+        .collect();
+    let committed = CommittedTrace::commit(&columns, coset, blowup_factor, transcript);
+    let trace_roots = committed.roots();
+    let trace_polys = committed.polys;
+    let trace_evals = committed.lde;
 
     let mut shifted_evals = Vec::with_capacity(w);
     for trace_poly in &trace_polys {
@@ -59,14 +162,6 @@ pub fn prove_fast<F: PrimeField>(
         shifted_evals.push(evals);
     }
 
-    let mut trace_roots = Vec::with_capacity(w);
-    for eval in trace_evals.clone() {
-        let tree = MerkleTree::new(eval);
-        let root = tree.root();
-        trace_roots.push(root);
-        transcript.digest(root);
-    }
-
     let z = transcript.generate_a_challenge();
     let mut trace_evals_at_z = Vec::new();
     let mut trace_evals_at_omega_z = Vec::new();
@@ -101,9 +196,9 @@ pub fn prove_fast<F: PrimeField>(
     }
 
     let last_point = trace_domain.element(t - 1);
+    let vanishing_evals = vanishing_evals_on_coset(t, coset, e); // x^T - 1
     let mut transition_zerofier_evals = Vec::with_capacity(e);
-    for eval_point in eval_points.iter().take(e) {
-        let vanishing_val = eval_point.pow([t as u64]) - F::one(); // x^T - 1
+    for (eval_point, vanishing_val) in eval_points.iter().zip(vanishing_evals) {
         let tz = vanishing_val / (*eval_point - last_point);
         transition_zerofier_evals.push(tz);
     }
@@ -1,11 +1,15 @@
-use ark_ff::{PrimeField, Zero};
+use ark_ff::PrimeField;
 use ark_poly::{
-    DenseMVPolynomial, DenseUVPolynomial, EvaluationDomain, Evaluations, GeneralEvaluationDomain,
-    multivariate::{SparsePolynomial, SparseTerm, Term},
+    DenseUVPolynomial, EvaluationDomain, Evaluations, GeneralEvaluationDomain,
+    multivariate::{SparsePolynomial, SparseTerm},
     univariate::DensePolynomial,
 };
+use ark_std::{vec, vec::Vec};
 
-use crate::polynomial::{domain, poly_pow, shift_poly};
+use crate::{
+    polynomial::{domain, multivariate::evaluate_symbolic, shift_poly},
+    stark::air::transition_zerofier,
+};
 
 pub(super) fn interpolate_trace<F: PrimeField>(trace: &[Vec<F>]) -> Vec<DensePolynomial<F>> {
     let trace_length = trace.len();
@@ -48,9 +52,7 @@ pub(super) fn transition_quotients<F: PrimeField>(
     transition_constraints: &[SparsePolynomial<F, SparseTerm>],
     domain: &GeneralEvaluationDomain<F>,
 ) -> Vec<DensePolynomial<F>> {
-    let t = domain.size();
     let omega = domain.group_gen();
-    let w = trace_polys.len();
 
     let mut polys = Vec::with_capacity(transition_constraints.len());
     let mut shifted_polys = Vec::with_capacity(transition_constraints.len());
@@ -60,26 +62,13 @@ pub(super) fn transition_quotients<F: PrimeField>(
         shifted_polys.push(shifted);
     }
 
-    let vanishing: DensePolynomial<F> = domain.vanishing_polynomial().into();
-    let last_point = domain.element(t - 1);
-    let exclude_last = DensePolynomial::from_coefficients_vec(vec![-last_point, F::one()]);
-    let transition_zerofier = vanishing / exclude_last;
+    let transition_zerofier = transition_zerofier(domain);
 
-    for constraint in transition_constraints {
-        let mut numerator = DensePolynomial::<F>::zero();
-        for (coeff, term) in constraint.terms() {
-            let mut mononial = DensePolynomial::from_coefficients_vec(vec![*coeff]);
-            for (var_index, power) in term.vars().iter().zip(term.powers()) {
-                let p = if *var_index < w {
-                    poly_pow(&trace_polys[*var_index], power)
-                } else {
-                    poly_pow(&shifted_polys[*var_index - w], power)
-                };
-                mononial = mononial * p;
-            }
-            numerator = numerator + mononial;
-        }
+    let substitutions: Vec<DensePolynomial<F>> =
+        trace_polys.iter().chain(&shifted_polys).cloned().collect();
 
+    for constraint in transition_constraints {
+        let numerator = evaluate_symbolic(constraint, &substitutions);
         let poly = numerator / transition_zerofier.clone();
         polys.push(poly);
     }
@@ -0,0 +1,111 @@
+use ark_ff::PrimeField;
+use ark_poly::univariate::DensePolynomial;
+use ark_std::vec::Vec;
+
+use crate::{
+    crypto::{
+        merkle::{MerkleProof, MerkleTree, commit_columns, open_row},
+        transcript::Transcript,
+    },
+    polynomial::{coeffs_padded, interpolate_columns, ntt::NttDomain, shift_poly},
+};
+
+// The prover preamble: trace columns interpolated over the trace subgroup,
+// extended onto a coset of the blown-up domain and committed column by column.
+pub struct CommittedTrace<F: PrimeField> {
+    pub polys: Vec<DensePolynomial<F>>,
+    pub lde: Vec<Vec<F>>,
+    pub trees: Vec<MerkleTree<F>>,
+}
+
+impl<F: PrimeField> CommittedTrace<F> {
+    // Evaluates every column on coset·<η>, |η| = blowup_factor · column length,
+    // and digests the roots into the transcript in column order.
+    pub fn commit(
+        columns: &[Vec<F>],
+        coset: F,
+        blowup_factor: usize,
+        transcript: &mut Transcript<F>,
+    ) -> Self {
+        let polys = interpolate_columns(columns);
+        let lde_size = columns.first().map_or(0, Vec::len) * blowup_factor;
+
+        let mut lde = Vec::with_capacity(polys.len());
+        if !polys.is_empty() {
+            let ntt = NttDomain::new(lde_size);
+            for poly in &polys {
+                let mut evals = coeffs_padded(&shift_poly(poly, coset), lde_size);
+                ntt.forward(&mut evals);
+                lde.push(evals);
+            }
+        }
+
+        let trees = commit_columns(&lde);
+        for tree in &trees {
+            transcript.digest(tree.root());
+        }
+
+        Self { polys, lde, trees }
+    }
+
+    pub fn roots(&self) -> Vec<F> {
+        self.trees.iter().map(MerkleTree::root).collect()
+    }
+
+    // Openings of one LDE row across all columns.
+    pub fn open(&self, row: usize) -> Vec<MerkleProof<F>> {
+        open_row(&self.trees, row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{FftField, Zero};
+    use ark_poly::Polynomial;
+
+    use super::*;
+    use crate::{
+        crypto::merkle::verify_row_openings,
+        field::Fq,
+        polynomial::{coset, subgroup},
+    };
+
+    fn columns() -> Vec<Vec<Fq>> {
+        (0..3u64)
+            .map(|c| (0..8u64).map(|i| Fq::from(c * 100 + i * i + 1)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_commit_interpolates_and_extends() {
+        let columns = columns();
+        let mut transcript = Transcript::new(Fq::zero());
+        let trace = CommittedTrace::commit(&columns, Fq::GENERATOR, 4, &mut transcript);
+
+        let lde_points = coset(Fq::GENERATOR, 32);
+        for ((poly, column), lde) in trace.polys.iter().zip(&columns).zip(&trace.lde) {
+            let on_subgroup: Vec<Fq> = subgroup(8).map(|x| poly.evaluate(&x)).collect();
+            assert_eq!(on_subgroup, *column);
+
+            let on_coset: Vec<Fq> = lde_points.iter().map(|x| poly.evaluate(x)).collect();
+            assert_eq!(on_coset, *lde);
+        }
+    }
+
+    #[test]
+    fn test_commit_roots_are_reproducible() {
+        let columns = columns();
+        let mut first = Transcript::new(Fq::zero());
+        let mut second = Transcript::new(Fq::zero());
+        let a = CommittedTrace::commit(&columns, Fq::GENERATOR, 4, &mut first);
+        let b = CommittedTrace::commit(&columns, Fq::GENERATOR, 4, &mut second);
+
+        assert_eq!(a.roots(), b.roots());
+        assert_eq!(a.roots().len(), columns.len());
+        assert_eq!(first.generate_a_challenge(), second.generate_a_challenge());
+        for (root, lde) in a.roots().iter().zip(&a.lde) {
+            assert_eq!(*root, MerkleTree::new(lde.clone()).root());
+        }
+        assert!(verify_row_openings(&a.roots(), 9, &a.open(9)));
+    }
+}
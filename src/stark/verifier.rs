@@ -1,9 +1,11 @@
 use ark_ff::PrimeField;
-use ark_poly::{EvaluationDomain, Polynomial};
+use ark_poly::{EvaluationDomain, GeneralEvaluationDomain};
+use ark_std::{vec, vec::Vec};
 
 use crate::{
     crypto::transcript::Transcript,
-    polynomial::domain,
+    field::arithmetic::checked_div,
+    polynomial::multivariate::try_evaluate,
     stark::{air::Air, prover::StarkProof},
 };
 
@@ -13,7 +15,17 @@ pub fn verify<F: PrimeField>(
     transcript: &mut Transcript<F>,
 ) -> anyhow::Result<()> {
     let t = air.original_trace_length;
-    let domain = domain::<F>(t);
+    let domain = match t {
+        0 => None,
+        _ => GeneralEvaluationDomain::<F>::new(t),
+    }
+    .ok_or_else(|| anyhow::anyhow!("unsupported trace length"))?;
+
+    if proof.trace_evals_at_z.len() != air.num_registers
+        || proof.trace_evals_at_omega_z.len() != air.num_registers
+    {
+        return Err(anyhow::anyhow!("wrong number of trace evaluations"));
+    }
 
     let num_boundary = air.boundary_constraints.len();
     let num_transition = air.transition_constraints.len();
@@ -32,20 +44,24 @@ pub fn verify<F: PrimeField>(
 
     let mut boundary_quotient_values = Vec::with_capacity(num_boundary);
     for constraint in &air.boundary_constraints {
+        let trace_eval = proof
+            .trace_evals_at_z
+            .get(constraint.register)
+            .ok_or_else(|| anyhow::anyhow!("boundary constraint on a missing register"))?;
         let omega_c = domain.element(constraint.cycle);
-        let q = (proof.trace_evals_at_z[constraint.register] - constraint.value) / (z - omega_c);
+        let q = checked_div(*trace_eval - constraint.value, z - omega_c)?;
         boundary_quotient_values.push(q);
     }
 
     let mut transition_quotient_values = Vec::with_capacity(num_transition);
     let last = domain.element(t - 1);
-    let zerofier_at_z = (z.pow(vec![t as u64]) - F::one()) / (z - last);
+    let zerofier_at_z = checked_div(z.pow(vec![t as u64]) - F::one(), z - last)?;
     for constraint in &air.transition_constraints {
         let mut point = proof.trace_evals_at_z.clone();
         point.extend(proof.trace_evals_at_omega_z.clone());
 
-        let c_val = constraint.evaluate(&point);
-        let q = c_val / zerofier_at_z;
+        let c_val = try_evaluate(constraint, &point)?;
+        let q = checked_div(c_val, zerofier_at_z)?;
         transition_quotient_values.push(q);
     }
 
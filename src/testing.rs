@@ -0,0 +1,44 @@
+use ark_ff::PrimeField;
+use ark_poly::{DenseUVPolynomial, univariate::DensePolynomial};
+use proptest::prelude::*;
+
+pub fn arb_field<F: PrimeField>() -> impl Strategy<Value = F> {
+    any::<[u8; 32]>().prop_map(|bytes| F::from_le_bytes_mod_order(&bytes))
+}
+
+pub fn arb_poly<F: PrimeField>(max_degree: usize) -> impl Strategy<Value = DensePolynomial<F>> {
+    proptest::collection::vec(arb_field::<F>(), 1..=max_degree + 1)
+        .prop_map(DensePolynomial::from_coefficients_vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::Fq;
+    use crate::polynomial::{domain, fast_evaluate_domain, fast_interpolate, poly_square};
+
+    proptest! {
+        #[test]
+        fn prop_field_distributive(a in arb_field::<Fq>(), b in arb_field::<Fq>(), c in arb_field::<Fq>()) {
+            prop_assert_eq!(a * (b + c), a * b + a * c);
+        }
+
+        #[test]
+        fn prop_field_associative(a in arb_field::<Fq>(), b in arb_field::<Fq>(), c in arb_field::<Fq>()) {
+            prop_assert_eq!((a * b) * c, a * (b * c));
+            prop_assert_eq!((a + b) + c, a + (b + c));
+        }
+
+        #[test]
+        fn prop_intt_ntt_roundtrip(poly in arb_poly::<Fq>(31)) {
+            let domain = domain(32);
+            let evals = fast_evaluate_domain(&poly, &domain);
+            prop_assert_eq!(fast_interpolate(evals, &domain), poly);
+        }
+
+        #[test]
+        fn prop_poly_square(poly in arb_poly::<Fq>(80)) {
+            prop_assert_eq!(poly_square(&poly), &poly * &poly);
+        }
+    }
+}
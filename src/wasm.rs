@@ -0,0 +1,136 @@
+use ark_ff::Zero;
+use ark_serialize::CanonicalDeserialize;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{
+    crypto::transcript::Transcript,
+    field::Fq,
+    stark::{air::Air, prover::StarkProof, verifier::verify},
+};
+
+// Verifies a compressed StarkProof<Fq> against its compressed Air<Fq>. Malformed
+// bytes of either kind count as a rejected proof.
+#[wasm_bindgen]
+pub fn verify_proof(proof_bytes: &[u8], public_inputs: &[u8]) -> bool {
+    try_verify(proof_bytes, public_inputs).is_ok()
+}
+
+fn try_verify(proof_bytes: &[u8], public_inputs: &[u8]) -> anyhow::Result<()> {
+    let proof = StarkProof::<Fq>::from_bytes(proof_bytes)?;
+    let air = Air::<Fq>::deserialize_compressed(public_inputs)
+        .map_err(|e| anyhow::anyhow!("malformed public inputs: {e}"))?;
+
+    let mut transcript = Transcript::new(Fq::zero());
+    verify(&proof, &air, &mut transcript)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_poly::{
+        DenseMVPolynomial,
+        multivariate::{SparsePolynomial, SparseTerm, Term},
+    };
+    use ark_serialize::CanonicalSerialize;
+
+    use super::*;
+    use crate::stark::{air::BoundaryConstraint, prover::prove};
+
+    fn squaring_proof() -> (Vec<u8>, Air<Fq>) {
+        let trace: Vec<Vec<Fq>> = [3u64, 9, 81, 6561]
+            .into_iter()
+            .map(|x| vec![Fq::from(x)])
+            .collect();
+        let air = Air {
+            num_registers: 1,
+            original_trace_length: 4,
+            transition_constraints: vec![SparsePolynomial::from_coefficients_vec(
+                2,
+                vec![
+                    (Fq::from(1), SparseTerm::new(vec![(1, 1)])),
+                    (-Fq::from(1), SparseTerm::new(vec![(0, 2)])),
+                ],
+            )],
+            boundary_constraints: vec![BoundaryConstraint {
+                cycle: 0,
+                register: 0,
+                value: Fq::from(3),
+            }],
+        };
+
+        let proof = prove(trace, &air, &mut Transcript::new(Fq::zero()));
+        (proof.to_bytes(), air)
+    }
+
+    fn air_bytes(air: &Air<Fq>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        air.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_verify_proof_bytes() {
+        let (proof, air) = squaring_proof();
+        assert!(verify_proof(&proof, &air_bytes(&air)));
+    }
+
+    #[test]
+    fn test_verify_proof_malformed_input() {
+        let (proof, air) = squaring_proof();
+        let air = air_bytes(&air);
+
+        for len in [0, 1, proof.len() / 2, proof.len() - 1] {
+            assert!(!verify_proof(&proof[..len], &air));
+        }
+        assert!(!verify_proof(&proof, &air[..air.len() - 1]));
+        assert!(!verify_proof(&proof, &[0xff; 16]));
+    }
+
+    #[test]
+    fn test_verify_proof_huge_length_prefix() {
+        let (proof, air) = squaring_proof();
+        let air = air_bytes(&air);
+
+        // domain_size, coset, queries, folding_factor, then the layers_root length
+        for len in [u64::MAX / 2, u64::MAX / 16] {
+            let mut forged = Vec::new();
+            for word in [8u64, 7, 1, 2, len] {
+                forged.extend(word.to_le_bytes());
+            }
+            assert_eq!(forged.len(), 40);
+            assert!(!verify_proof(&forged, &air));
+
+            // num_registers, original_trace_length, then the constraints length
+            let mut forged = Vec::new();
+            for word in [1u64, 4, len] {
+                forged.extend(word.to_le_bytes());
+            }
+            assert!(!verify_proof(&proof, &forged));
+
+            // and every other word, including the length prefixes nested inside the
+            // constraint. Word 3 is the constraint's declared num_vars, only an upper
+            // bound that verification never reads: evaluation checks the variables
+            // the terms actually use, so a larger value still verifies.
+            for word in (0..air.len() / 8).filter(|&word| word != 3) {
+                let mut forged = air.clone();
+                forged[8 * word..8 * word + 8].copy_from_slice(&len.to_le_bytes());
+                assert!(!verify_proof(&proof, &forged));
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_proof_mismatched_air() {
+        let (proof, mut air) = squaring_proof();
+
+        air.boundary_constraints[0].register = 5;
+        assert!(!verify_proof(&proof, &air_bytes(&air)));
+
+        air.boundary_constraints[0].register = 0;
+        air.num_registers = 2;
+        assert!(!verify_proof(&proof, &air_bytes(&air)));
+
+        air.num_registers = 1;
+        air.original_trace_length = 0;
+        assert!(!verify_proof(&proof, &air_bytes(&air)));
+    }
+}